@@ -0,0 +1,52 @@
+use std::borrow::Cow;
+
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use squalid::{CowExt, CowStrExt};
+
+fn large_string(len: usize) -> String {
+    "abcdefghij".repeat(len / 10)
+}
+
+fn bench_map_borrowed_unchanged(c: &mut Criterion) {
+    let mut group = c.benchmark_group("map_borrowed_unchanged");
+    for len in [1_000, 100_000, 1_000_000] {
+        let string = large_string(len);
+        group.bench_with_input(
+            BenchmarkId::new("ptr_identity", len),
+            &string,
+            |b, string| {
+                b.iter_batched(
+                    || Cow::<str>::Owned(string.clone()),
+                    |cow| black_box(cow.map_borrowed(str::trim)),
+                    BatchSize::SmallInput,
+                )
+            },
+        );
+        group.bench_with_input(BenchmarkId::new("value_eq", len), &string, |b, string| {
+            b.iter_batched(
+                || Cow::<str>::Owned(string.clone()),
+                |cow| black_box(cow.map_borrowed_eq(str::trim)),
+                BatchSize::SmallInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+fn bench_trimmed_owned(c: &mut Criterion) {
+    let mut group = c.benchmark_group("trimmed_owned");
+    for len in [1_000, 100_000, 1_000_000] {
+        let string = large_string(len);
+        group.bench_with_input(BenchmarkId::from_parameter(len), &string, |b, string| {
+            b.iter_batched(
+                || Cow::<str>::Owned(string.clone()),
+                |cow| black_box(cow.trimmed()),
+                BatchSize::SmallInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_map_borrowed_unchanged, bench_trimmed_owned);
+criterion_main!(benches);
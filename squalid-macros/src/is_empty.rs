@@ -0,0 +1,118 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{
+    parse_quote, spanned::Spanned, Attribute, Data, DeriveInput, Error, Fields, Member, Path,
+    Result,
+};
+
+enum FieldStrategy {
+    IsEmpty,
+    Skip,
+    With(Path),
+}
+
+fn field_strategy(attrs: &[Attribute]) -> Result<FieldStrategy> {
+    let mut strategy = FieldStrategy::IsEmpty;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("is_empty")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") {
+                strategy = FieldStrategy::Skip;
+                Ok(())
+            } else if meta.path.is_ident("with") {
+                strategy = FieldStrategy::With(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `skip` or `with = path`"))
+            }
+        })?;
+    }
+    Ok(strategy)
+}
+
+fn is_empty_variant(attrs: &[Attribute]) -> Result<bool> {
+    let mut is_empty = false;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("is_empty")) {
+        attr.meta.require_path_only()?;
+        is_empty = true;
+    }
+    Ok(is_empty)
+}
+
+pub fn derive(mut input: DeriveInput) -> Result<TokenStream> {
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let mut checks = vec![];
+            for (index, field) in data.fields.iter().enumerate() {
+                let member = match &field.ident {
+                    Some(ident) => Member::Named(ident.clone()),
+                    None => Member::Unnamed(index.into()),
+                };
+                match field_strategy(&field.attrs)? {
+                    FieldStrategy::Skip => {}
+                    FieldStrategy::With(path) => {
+                        checks.push(quote!(#path(&self.#member)));
+                    }
+                    FieldStrategy::IsEmpty => {
+                        let ty = &field.ty;
+                        input
+                            .generics
+                            .make_where_clause()
+                            .predicates
+                            .push(parse_quote!(#ty: ::squalid::IsEmpty));
+                        checks.push(quote!(::squalid::IsEmpty::_is_empty(&self.#member)));
+                    }
+                }
+            }
+            quote!(true #(&& #checks)*)
+        }
+        Data::Enum(data) => {
+            let arms = data
+                .variants
+                .iter()
+                .map(|variant| {
+                    let ident = &variant.ident;
+                    let is_empty = is_empty_variant(&variant.attrs)?;
+                    if let Some(field) = variant.fields.iter().find(|field| {
+                        field
+                            .attrs
+                            .iter()
+                            .any(|attr| attr.path().is_ident("is_empty"))
+                    }) {
+                        return Err(Error::new(
+                            field.span(),
+                            "#[is_empty] field attributes are only supported on structs",
+                        ));
+                    }
+                    let pattern = match &variant.fields {
+                        Fields::Unit => quote!(Self::#ident),
+                        Fields::Named(_) => quote!(Self::#ident { .. }),
+                        Fields::Unnamed(_) => quote!(Self::#ident(..)),
+                    };
+                    Ok(quote!(#pattern => #is_empty))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            quote! {
+                match self {
+                    #(#arms,)*
+                }
+            }
+        }
+        Data::Union(data) => {
+            return Err(Error::new(
+                data.union_token.span,
+                "IsEmpty cannot be derived for unions",
+            ));
+        }
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::squalid::IsEmpty for #name #ty_generics #where_clause {
+            fn _is_empty(&self) -> bool {
+                #body
+            }
+        }
+    }
+    .into_token_stream())
+}
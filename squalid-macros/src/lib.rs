@@ -0,0 +1,75 @@
+mod is_empty;
+
+use proc_macro::TokenStream;
+use quote::ToTokens;
+use syn::{parse_macro_input, DeriveInput, LitStr};
+
+#[cfg(feature = "regex")]
+#[proc_macro]
+pub fn regex_literal(input: TokenStream) -> TokenStream {
+    validated_literal(input, validate_regex)
+}
+
+#[cfg(feature = "fancy-regex")]
+#[proc_macro]
+pub fn fancy_regex_literal(input: TokenStream) -> TokenStream {
+    validated_literal(input, validate_fancy_regex)
+}
+
+#[proc_macro_derive(IsEmpty, attributes(is_empty))]
+pub fn derive_is_empty(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match is_empty::derive(input) {
+        Ok(output) => output,
+        Err(error) => error.into_compile_error(),
+    }
+    .into()
+}
+
+#[allow(dead_code)]
+fn validated_literal(
+    input: TokenStream,
+    validate: impl FnOnce(&str) -> Result<(), String>,
+) -> TokenStream {
+    let literal = parse_macro_input!(input as LitStr);
+    match validate(&literal.value()) {
+        Ok(()) => literal.into_token_stream(),
+        Err(message) => syn::Error::new(literal.span(), message).into_compile_error(),
+    }
+    .into()
+}
+
+#[cfg(feature = "regex")]
+fn validate_regex(pattern: &str) -> Result<(), String> {
+    regex::Regex::new(pattern)
+        .map(|_| ())
+        .map_err(|error| format!("invalid regex: {error}"))
+}
+
+#[cfg(feature = "fancy-regex")]
+fn validate_fancy_regex(pattern: &str) -> Result<(), String> {
+    fancy_regex::Regex::new(pattern)
+        .map(|_| ())
+        .map_err(|error| format!("invalid fancy regex: {error}"))
+}
+
+#[cfg(all(test, any(feature = "regex", feature = "fancy-regex")))]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_validate_regex() {
+        assert!(validate_regex(r"^f(o+)$").is_ok());
+        assert!(validate_regex(r"^f(o+$").is_err());
+        assert!(validate_regex(r"(\w)\1").is_err());
+        assert!(validate_regex(r"\<foo\>").is_ok());
+    }
+
+    #[cfg(feature = "fancy-regex")]
+    #[test]
+    fn test_validate_fancy_regex() {
+        assert!(validate_fancy_regex(r"(\w)\1").is_ok());
+        assert!(validate_fancy_regex(r"(\w)\1(").is_err());
+    }
+}
@@ -0,0 +1,523 @@
+#[cfg(feature = "alloc")]
+use alloc::{
+    collections::{BTreeMap, BTreeSet, BinaryHeap, VecDeque},
+    string::String,
+    vec::Vec,
+};
+#[cfg(feature = "alloc")]
+use core::borrow::Borrow;
+#[cfg(feature = "std")]
+use std::{
+    collections::{HashMap, HashSet},
+    hash,
+};
+
+#[cfg(feature = "indexmap")]
+use indexmap::{IndexMap, IndexSet};
+
+pub trait AndExtend<TItem>: Sized {
+    fn and_extend(self, iter: impl IntoIterator<Item = TItem>) -> Self;
+}
+
+#[cfg(feature = "alloc")]
+macro_rules! impl_and_extend {
+    ($type:ty $(, $generic:tt)* $(,)?) => {
+        impl<TExtendItem, $($generic),*> AndExtend<TExtendItem> for $type
+        where
+            $type: Extend<TExtendItem>,
+        {
+            fn and_extend(mut self, iter: impl IntoIterator<Item = TExtendItem>) -> Self {
+                self.extend(iter);
+                self
+            }
+        }
+    };
+}
+
+#[cfg(feature = "alloc")]
+impl_and_extend!(VecDeque<TItem>, TItem);
+#[cfg(feature = "alloc")]
+impl_and_extend!(String);
+#[cfg(feature = "alloc")]
+impl_and_extend!(BTreeSet<TItem>, TItem);
+#[cfg(feature = "alloc")]
+impl_and_extend!(BinaryHeap<TItem>, TItem);
+#[cfg(feature = "std")]
+impl_and_extend!(HashSet<TItem, TBuildHasher>, TItem, TBuildHasher);
+#[cfg(feature = "indexmap")]
+impl_and_extend!(IndexSet<TItem, TBuildHasher>, TItem, TBuildHasher);
+
+pub trait AndInsert: Sized {
+    type Entry;
+
+    fn and_insert(self, entry: Self::Entry) -> Self;
+}
+
+#[cfg(feature = "alloc")]
+impl<TItem: Ord> AndInsert for BTreeSet<TItem> {
+    type Entry = TItem;
+
+    fn and_insert(mut self, item: TItem) -> Self {
+        self.insert(item);
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<TKey: Ord, TValue> AndInsert for BTreeMap<TKey, TValue> {
+    type Entry = (TKey, TValue);
+
+    fn and_insert(mut self, (key, value): (TKey, TValue)) -> Self {
+        self.insert(key, value);
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<TItem: Ord> AndInsert for BinaryHeap<TItem> {
+    type Entry = TItem;
+
+    fn and_insert(mut self, item: TItem) -> Self {
+        self.push(item);
+        self
+    }
+}
+
+#[cfg(feature = "std")]
+impl<TItem, TBuildHasher> AndInsert for HashSet<TItem, TBuildHasher>
+where
+    TItem: Eq + hash::Hash,
+    TBuildHasher: hash::BuildHasher,
+{
+    type Entry = TItem;
+
+    fn and_insert(mut self, item: TItem) -> Self {
+        self.insert(item);
+        self
+    }
+}
+
+#[cfg(feature = "std")]
+impl<TKey, TValue, TBuildHasher> AndInsert for HashMap<TKey, TValue, TBuildHasher>
+where
+    TKey: Eq + hash::Hash,
+    TBuildHasher: hash::BuildHasher,
+{
+    type Entry = (TKey, TValue);
+
+    fn and_insert(mut self, (key, value): (TKey, TValue)) -> Self {
+        self.insert(key, value);
+        self
+    }
+}
+
+#[cfg(feature = "indexmap")]
+impl<TItem, TBuildHasher> AndInsert for IndexSet<TItem, TBuildHasher>
+where
+    TItem: Eq + hash::Hash,
+    TBuildHasher: hash::BuildHasher,
+{
+    type Entry = TItem;
+
+    fn and_insert(mut self, item: TItem) -> Self {
+        self.insert(item);
+        self
+    }
+}
+
+#[cfg(feature = "indexmap")]
+impl<TKey, TValue, TBuildHasher> AndInsert for IndexMap<TKey, TValue, TBuildHasher>
+where
+    TKey: Eq + hash::Hash,
+    TBuildHasher: hash::BuildHasher,
+{
+    type Entry = (TKey, TValue);
+
+    fn and_insert(mut self, (key, value): (TKey, TValue)) -> Self {
+        self.insert(key, value);
+        self
+    }
+}
+
+pub trait AndRemove<TKey: ?Sized>: Sized {
+    fn and_remove(self, key: &TKey) -> Self;
+}
+
+#[cfg(feature = "alloc")]
+impl<TItem, TKey> AndRemove<TKey> for BTreeSet<TItem>
+where
+    TItem: Borrow<TKey> + Ord,
+    TKey: Ord + ?Sized,
+{
+    fn and_remove(mut self, key: &TKey) -> Self {
+        self.remove(key);
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<TKey, TValue, TBorrowed> AndRemove<TBorrowed> for BTreeMap<TKey, TValue>
+where
+    TKey: Borrow<TBorrowed> + Ord,
+    TBorrowed: Ord + ?Sized,
+{
+    fn and_remove(mut self, key: &TBorrowed) -> Self {
+        self.remove(key);
+        self
+    }
+}
+
+#[cfg(feature = "std")]
+impl<TItem, TKey, TBuildHasher> AndRemove<TKey> for HashSet<TItem, TBuildHasher>
+where
+    TItem: Borrow<TKey> + Eq + hash::Hash,
+    TKey: Eq + hash::Hash + ?Sized,
+    TBuildHasher: hash::BuildHasher,
+{
+    fn and_remove(mut self, key: &TKey) -> Self {
+        self.remove(key);
+        self
+    }
+}
+
+#[cfg(feature = "std")]
+impl<TKey, TValue, TBorrowed, TBuildHasher> AndRemove<TBorrowed>
+    for HashMap<TKey, TValue, TBuildHasher>
+where
+    TKey: Borrow<TBorrowed> + Eq + hash::Hash,
+    TBorrowed: Eq + hash::Hash + ?Sized,
+    TBuildHasher: hash::BuildHasher,
+{
+    fn and_remove(mut self, key: &TBorrowed) -> Self {
+        self.remove(key);
+        self
+    }
+}
+
+#[cfg(feature = "indexmap")]
+impl<TItem, TKey, TBuildHasher> AndRemove<TKey> for IndexSet<TItem, TBuildHasher>
+where
+    TItem: Borrow<TKey> + Eq + hash::Hash,
+    TKey: Eq + hash::Hash + ?Sized,
+    TBuildHasher: hash::BuildHasher,
+{
+    fn and_remove(mut self, key: &TKey) -> Self {
+        self.shift_remove(key);
+        self
+    }
+}
+
+#[cfg(feature = "indexmap")]
+impl<TKey, TValue, TBorrowed, TBuildHasher> AndRemove<TBorrowed>
+    for IndexMap<TKey, TValue, TBuildHasher>
+where
+    TKey: Borrow<TBorrowed> + Eq + hash::Hash,
+    TBorrowed: Eq + hash::Hash + ?Sized,
+    TBuildHasher: hash::BuildHasher,
+{
+    fn and_remove(mut self, key: &TBorrowed) -> Self {
+        self.shift_remove(key);
+        self
+    }
+}
+
+pub trait AndRetain<TPredicate>: Sized {
+    fn and_retain(self, predicate: TPredicate) -> Self;
+}
+
+#[cfg(feature = "alloc")]
+impl<TItem, TPredicate> AndRetain<TPredicate> for VecDeque<TItem>
+where
+    TPredicate: FnMut(&TItem) -> bool,
+{
+    fn and_retain(mut self, predicate: TPredicate) -> Self {
+        self.retain(predicate);
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<TPredicate> AndRetain<TPredicate> for String
+where
+    TPredicate: FnMut(char) -> bool,
+{
+    fn and_retain(mut self, predicate: TPredicate) -> Self {
+        self.retain(predicate);
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<TItem, TPredicate> AndRetain<TPredicate> for BTreeSet<TItem>
+where
+    TItem: Ord,
+    TPredicate: FnMut(&TItem) -> bool,
+{
+    fn and_retain(mut self, predicate: TPredicate) -> Self {
+        self.retain(predicate);
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<TKey, TValue, TPredicate> AndRetain<TPredicate> for BTreeMap<TKey, TValue>
+where
+    TKey: Ord,
+    TPredicate: FnMut(&TKey, &mut TValue) -> bool,
+{
+    fn and_retain(mut self, predicate: TPredicate) -> Self {
+        self.retain(predicate);
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<TItem, TPredicate> AndRetain<TPredicate> for BinaryHeap<TItem>
+where
+    TItem: Ord,
+    TPredicate: FnMut(&TItem) -> bool,
+{
+    fn and_retain(mut self, predicate: TPredicate) -> Self {
+        self.retain(predicate);
+        self
+    }
+}
+
+#[cfg(feature = "std")]
+impl<TItem, TBuildHasher, TPredicate> AndRetain<TPredicate> for HashSet<TItem, TBuildHasher>
+where
+    TItem: Eq + hash::Hash,
+    TBuildHasher: hash::BuildHasher,
+    TPredicate: FnMut(&TItem) -> bool,
+{
+    fn and_retain(mut self, predicate: TPredicate) -> Self {
+        self.retain(predicate);
+        self
+    }
+}
+
+#[cfg(feature = "std")]
+impl<TKey, TValue, TBuildHasher, TPredicate> AndRetain<TPredicate>
+    for HashMap<TKey, TValue, TBuildHasher>
+where
+    TKey: Eq + hash::Hash,
+    TBuildHasher: hash::BuildHasher,
+    TPredicate: FnMut(&TKey, &mut TValue) -> bool,
+{
+    fn and_retain(mut self, predicate: TPredicate) -> Self {
+        self.retain(predicate);
+        self
+    }
+}
+
+#[cfg(feature = "indexmap")]
+impl<TItem, TBuildHasher, TPredicate> AndRetain<TPredicate> for IndexSet<TItem, TBuildHasher>
+where
+    TPredicate: FnMut(&TItem) -> bool,
+{
+    fn and_retain(mut self, predicate: TPredicate) -> Self {
+        self.retain(predicate);
+        self
+    }
+}
+
+#[cfg(feature = "indexmap")]
+impl<TKey, TValue, TBuildHasher, TPredicate> AndRetain<TPredicate>
+    for IndexMap<TKey, TValue, TBuildHasher>
+where
+    TPredicate: FnMut(&TKey, &mut TValue) -> bool,
+{
+    fn and_retain(mut self, predicate: TPredicate) -> Self {
+        self.retain(predicate);
+        self
+    }
+}
+
+pub trait AndClear: Sized {
+    fn and_clear(self) -> Self;
+}
+
+#[cfg(feature = "alloc")]
+macro_rules! impl_and_clear {
+    ($type:ty $(, $generic:tt)* $(,)?) => {
+        impl<$($generic),*> AndClear for $type {
+            fn and_clear(mut self) -> Self {
+                self.clear();
+                self
+            }
+        }
+    };
+}
+
+#[cfg(feature = "alloc")]
+impl_and_clear!(Vec<TItem>, TItem);
+#[cfg(feature = "alloc")]
+impl_and_clear!(VecDeque<TItem>, TItem);
+#[cfg(feature = "alloc")]
+impl_and_clear!(String);
+#[cfg(feature = "alloc")]
+impl_and_clear!(BTreeSet<TItem>, TItem);
+#[cfg(feature = "alloc")]
+impl_and_clear!(BTreeMap<TKey, TValue>, TKey, TValue);
+#[cfg(feature = "alloc")]
+impl_and_clear!(BinaryHeap<TItem>, TItem);
+#[cfg(feature = "std")]
+impl_and_clear!(HashSet<TItem, TBuildHasher>, TItem, TBuildHasher);
+#[cfg(feature = "std")]
+impl_and_clear!(HashMap<TKey, TValue, TBuildHasher>, TKey, TValue, TBuildHasher);
+#[cfg(feature = "indexmap")]
+impl_and_clear!(IndexSet<TItem, TBuildHasher>, TItem, TBuildHasher);
+#[cfg(feature = "indexmap")]
+impl_and_clear!(IndexMap<TKey, TValue, TBuildHasher>, TKey, TValue, TBuildHasher);
+
+#[cfg(feature = "alloc")]
+pub trait AndPushStr: Sized {
+    fn and_push_str(self, string: &str) -> Self;
+}
+
+#[cfg(feature = "alloc")]
+impl AndPushStr for String {
+    fn and_push_str(mut self, string: &str) -> Self {
+        self.push_str(string);
+        self
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_and_extend() {
+        assert_eq!(VecDeque::from([1]).and_extend([2]), VecDeque::from([1, 2]));
+        assert_eq!("foo".to_owned().and_extend(['b', 'a']), "fooba");
+        assert_eq!("foo".to_owned().and_extend(["bar"]), "foobar");
+        assert_eq!(BTreeSet::from([2]).and_extend([1]), BTreeSet::from([1, 2]));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_and_extend_hash_set() {
+        assert_eq!(HashSet::from([1]).and_extend([2, 1]), HashSet::from([1, 2]));
+    }
+
+    #[test]
+    fn test_and_insert() {
+        assert_eq!(
+            BTreeSet::from([1]).and_insert(2).and_insert(1),
+            BTreeSet::from([1, 2])
+        );
+        assert_eq!(
+            BTreeMap::from([("foo", 1)]).and_insert(("foo", 2)),
+            BTreeMap::from([("foo", 2)])
+        );
+        assert_eq!(
+            BinaryHeap::from([1]).and_insert(3).into_sorted_vec(),
+            vec![1, 3]
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_and_insert_hash() {
+        assert_eq!(HashSet::new().and_insert("foo"), HashSet::from(["foo"]));
+        assert_eq!(
+            HashMap::new().and_insert(("foo", 1)),
+            HashMap::from([("foo", 1)])
+        );
+    }
+
+    #[test]
+    fn test_and_remove() {
+        let btree_set = BTreeSet::from(["foo".to_owned(), "bar".to_owned()]);
+        assert_eq!(
+            btree_set.and_remove("foo"),
+            BTreeSet::from(["bar".to_owned()])
+        );
+        assert_eq!(
+            BTreeMap::from([(1, "foo"), (2, "bar")]).and_remove(&1),
+            BTreeMap::from([(2, "bar")])
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_and_remove_hash() {
+        let hash_map = HashMap::from([("foo".to_owned(), 1), ("bar".to_owned(), 2)]);
+        assert_eq!(
+            hash_map.and_remove("foo").and_remove("baz"),
+            HashMap::from([("bar".to_owned(), 2)])
+        );
+        assert_eq!(HashSet::from([1, 2]).and_remove(&2), HashSet::from([1]));
+    }
+
+    #[test]
+    fn test_and_retain() {
+        assert_eq!(
+            VecDeque::from([1, 2, 3]).and_retain(|value| value % 2 == 1),
+            VecDeque::from([1, 3])
+        );
+        assert_eq!("f o o".to_owned().and_retain(|c| c != ' '), "foo");
+        assert_eq!(
+            BTreeMap::from([(1, "foo"), (2, "bar")]).and_retain(|&key, _| key > 1),
+            BTreeMap::from([(2, "bar")])
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_and_retain_hash() {
+        assert_eq!(
+            HashSet::from([1, 2, 3]).and_retain(|&value| value > 1),
+            HashSet::from([2, 3])
+        );
+        assert_eq!(
+            HashMap::from([("foo", 1), ("bar", 2)]).and_retain(|_, value| *value > 1),
+            HashMap::from([("bar", 2)])
+        );
+    }
+
+    #[test]
+    fn test_and_clear() {
+        assert_eq!(vec![1].and_clear(), Vec::<i32>::new());
+        assert_eq!("foo".to_owned().and_clear().and_push_str("bar"), "bar");
+        assert_eq!(BTreeSet::from([1]).and_clear(), BTreeSet::new());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_and_clear_hash() {
+        assert_eq!(HashMap::from([(1, 2)]).and_clear(), HashMap::new());
+    }
+
+    #[cfg(feature = "indexmap")]
+    #[test]
+    fn test_index_collections() {
+        let index_map = IndexMap::from([("foo", 1), ("bar", 2), ("baz", 3)])
+            .and_remove("foo")
+            .and_insert(("qux", 4))
+            .and_retain(|_, value| *value % 2 == 0);
+        assert_eq!(
+            index_map.into_iter().collect::<Vec<_>>(),
+            vec![("bar", 2), ("qux", 4)]
+        );
+
+        let index_set = IndexSet::from([3, 1])
+            .and_insert(2)
+            .and_remove(&3)
+            .and_extend([0]);
+        assert_eq!(index_set.iter().copied().collect::<Vec<_>>(), vec![1, 2, 0]);
+        assert!(index_set
+            .and_retain(|&value| value > 0)
+            .and_clear()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_and_push_str() {
+        assert_eq!(
+            String::new().and_push_str("foo").and_push_str("bar"),
+            "foobar"
+        );
+    }
+}
@@ -1,32 +1,415 @@
-use std::{collections::HashSet, hash};
+#[cfg(feature = "alloc")]
+use alloc::{
+    collections::{BTreeMap, BTreeSet, VecDeque},
+    string::String,
+    vec::Vec,
+};
+use core::{
+    borrow::Borrow,
+    ops::{Deref, Range, RangeInclusive},
+};
+#[cfg(feature = "std")]
+use std::{
+    collections::{HashMap, HashSet},
+    hash,
+};
 
-pub trait Contains<TItem> {
+#[cfg(feature = "indexmap")]
+use indexmap::{IndexMap, IndexSet};
+
+/// Membership checks are linear for slices, `Vec` and `VecDeque`; wrap already sorted data in
+/// [`SortedSlice`] to get binary-search lookups, it is not detected automatically.
+pub trait Contains<TItem: ?Sized> {
     fn contains_(&self, item: &TItem) -> bool;
+
+    fn contains_any_<'item>(&self, items: impl IntoIterator<Item = &'item TItem>) -> bool
+    where
+        TItem: 'item,
+    {
+        items.into_iter().any(|item| self.contains_(item))
+    }
+
+    fn contains_all_<'item>(&self, items: impl IntoIterator<Item = &'item TItem>) -> bool
+    where
+        TItem: 'item,
+    {
+        items.into_iter().all(|item| self.contains_(item))
+    }
+
+    fn contains_none_<'item>(&self, items: impl IntoIterator<Item = &'item TItem>) -> bool
+    where
+        TItem: 'item,
+    {
+        !self.contains_any_(items)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SortedSlice<'a, T>(pub(crate) &'a [T]);
+
+impl<'a, T: Ord> SortedSlice<'a, T> {
+    pub fn new(slice: &'a [T]) -> Self {
+        debug_assert!(slice.is_sorted(), "expected sorted slice");
+        Self(slice)
+    }
+}
+
+impl<T> Deref for SortedSlice<'_, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.0
+    }
+}
+
+impl<T, TItem> Contains<TItem> for SortedSlice<'_, T>
+where
+    T: Borrow<TItem>,
+    TItem: Ord + ?Sized,
+{
+    fn contains_(&self, item: &TItem) -> bool {
+        self.0
+            .binary_search_by(|value| value.borrow().cmp(item))
+            .is_ok()
+    }
+}
+
+impl<T, TItem> Contains<TItem> for [T]
+where
+    T: PartialEq<TItem>,
+    TItem: ?Sized,
+{
+    fn contains_(&self, item: &TItem) -> bool {
+        self.iter().any(|value| value == item)
+    }
+}
+
+impl<T, TItem, const T_LEN: usize> Contains<TItem> for [T; T_LEN]
+where
+    T: PartialEq<TItem>,
+    TItem: ?Sized,
+{
+    fn contains_(&self, item: &TItem) -> bool {
+        self.as_slice().contains_(item)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, TItem> Contains<TItem> for Vec<T>
+where
+    T: PartialEq<TItem>,
+    TItem: ?Sized,
+{
+    fn contains_(&self, item: &TItem) -> bool {
+        self.as_slice().contains_(item)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, TItem> Contains<TItem> for VecDeque<T>
+where
+    T: PartialEq<TItem>,
+    TItem: ?Sized,
+{
+    fn contains_(&self, item: &TItem) -> bool {
+        self.iter().any(|value| value == item)
+    }
+}
+
+impl<T, TItem> Contains<TItem> for Option<T>
+where
+    T: PartialEq<TItem>,
+    TItem: ?Sized,
+{
+    fn contains_(&self, item: &TItem) -> bool {
+        matches!(self, Some(value) if value == item)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, TItem, TBuildHasher> Contains<TItem> for HashSet<T, TBuildHasher>
+where
+    T: Borrow<TItem> + Eq + hash::Hash,
+    TItem: Eq + hash::Hash + ?Sized,
+    TBuildHasher: hash::BuildHasher,
+{
+    fn contains_(&self, item: &TItem) -> bool {
+        self.contains(item)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<TKey, TValue, TItem, TBuildHasher> Contains<TItem> for HashMap<TKey, TValue, TBuildHasher>
+where
+    TKey: Borrow<TItem> + Eq + hash::Hash,
+    TItem: Eq + hash::Hash + ?Sized,
+    TBuildHasher: hash::BuildHasher,
+{
+    fn contains_(&self, item: &TItem) -> bool {
+        self.contains_key(item)
+    }
+}
+
+#[cfg(feature = "indexmap")]
+impl<T, TItem, TBuildHasher> Contains<TItem> for IndexSet<T, TBuildHasher>
+where
+    T: Borrow<TItem> + Eq + hash::Hash,
+    TItem: Eq + hash::Hash + ?Sized,
+    TBuildHasher: hash::BuildHasher,
+{
+    fn contains_(&self, item: &TItem) -> bool {
+        self.contains(item)
+    }
+}
+
+#[cfg(feature = "indexmap")]
+impl<TKey, TValue, TItem, TBuildHasher> Contains<TItem> for IndexMap<TKey, TValue, TBuildHasher>
+where
+    TKey: Borrow<TItem> + Eq + hash::Hash,
+    TItem: Eq + hash::Hash + ?Sized,
+    TBuildHasher: hash::BuildHasher,
+{
+    fn contains_(&self, item: &TItem) -> bool {
+        self.contains_key(item)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, TItem> Contains<TItem> for BTreeSet<T>
+where
+    T: Borrow<TItem> + Ord,
+    TItem: Ord + ?Sized,
+{
+    fn contains_(&self, item: &TItem) -> bool {
+        self.contains(item)
+    }
 }
 
-impl<T> Contains<T> for [T]
+#[cfg(feature = "alloc")]
+impl<TKey, TValue, TItem> Contains<TItem> for BTreeMap<TKey, TValue>
 where
-    T: PartialEq,
+    TKey: Borrow<TItem> + Ord,
+    TItem: Ord + ?Sized,
 {
-    fn contains_(&self, item: &T) -> bool {
+    fn contains_(&self, item: &TItem) -> bool {
+        self.contains_key(item)
+    }
+}
+
+impl Contains<str> for str {
+    fn contains_(&self, item: &str) -> bool {
         self.contains(item)
     }
 }
 
-impl<T> Contains<T> for HashSet<T>
+impl Contains<char> for str {
+    fn contains_(&self, item: &char) -> bool {
+        self.contains(*item)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Contains<str> for String {
+    fn contains_(&self, item: &str) -> bool {
+        self.as_str().contains_(item)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Contains<char> for String {
+    fn contains_(&self, item: &char) -> bool {
+        self.as_str().contains_(item)
+    }
+}
+
+impl<T, TItem> Contains<TItem> for Range<T>
 where
-    T: PartialEq + Eq + hash::Hash,
+    T: PartialOrd + PartialOrd<TItem>,
+    TItem: PartialOrd<T> + ?Sized,
 {
-    fn contains_(&self, item: &T) -> bool {
+    fn contains_(&self, item: &TItem) -> bool {
         self.contains(item)
     }
 }
 
-impl<T, const T_LEN: usize> Contains<T> for [T; T_LEN]
+impl<T, TItem> Contains<TItem> for RangeInclusive<T>
 where
-    T: PartialEq,
+    T: PartialOrd + PartialOrd<TItem>,
+    TItem: PartialOrd<T> + ?Sized,
 {
-    fn contains_(&self, item: &T) -> bool {
+    fn contains_(&self, item: &TItem) -> bool {
         self.contains(item)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slice() {
+        let slice: &[_] = &["foo".to_owned(), "bar".to_owned()];
+        assert!(slice.contains_(&"foo".to_owned()));
+        assert!(slice.contains_("foo"));
+        assert!(!slice.contains_("baz"));
+    }
+
+    #[test]
+    fn test_array() {
+        assert!([1, 2].contains_(&1));
+        assert!(!["foo", "bar"].contains_(&"baz"));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_vec() {
+        let vec = vec!["foo".to_owned()];
+        assert!(vec.contains_("foo"));
+        assert!(!vec.contains_("bar"));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_vec_deque() {
+        let vec_deque = VecDeque::from([1, 2]);
+        assert!(vec_deque.contains_(&2));
+        assert!(!vec_deque.contains_(&3));
+    }
+
+    #[test]
+    fn test_option() {
+        assert!(Some(1).contains_(&1));
+        assert!(!Some(1).contains_(&2));
+        assert!(!None::<i32>.contains_(&1));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_hash_set() {
+        let set_of_strings = HashSet::from(["foo".to_owned()]);
+        assert!(set_of_strings.contains_("foo"));
+        assert!(set_of_strings.contains_(&"foo".to_owned()));
+        assert!(!set_of_strings.contains_("bar"));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_hash_map() {
+        let hash_map = HashMap::from([("foo".to_owned(), 1)]);
+        assert!(hash_map.contains_("foo"));
+        assert!(!hash_map.contains_("bar"));
+    }
+
+    #[cfg(feature = "indexmap")]
+    #[test]
+    fn test_index_set_and_map() {
+        let index_set = IndexSet::from(["foo".to_owned()]);
+        assert!(index_set.contains_("foo"));
+        assert!(!index_set.contains_("bar"));
+
+        let index_map = IndexMap::from([("foo".to_owned(), 1)]);
+        assert!(index_map.contains_("foo"));
+        assert!(!index_map.contains_all_(["foo", "bar"]));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_btree_set() {
+        let btree_set = BTreeSet::from(["foo".to_owned()]);
+        assert!(btree_set.contains_("foo"));
+        assert!(!btree_set.contains_("bar"));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_btree_map() {
+        let btree_map = BTreeMap::from([("foo".to_owned(), 1)]);
+        assert!(btree_map.contains_("foo"));
+        assert!(!btree_map.contains_("bar"));
+    }
+
+    #[test]
+    fn test_str() {
+        assert!("foobar".contains_("oba"));
+        assert!(!"foobar".contains_("baz"));
+        assert!("foobar".contains_(&'f'));
+        assert!(!"foobar".contains_(&'z'));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_string() {
+        let string = "foobar".to_owned();
+        assert!(string.contains_("oba"));
+        assert!(!string.contains_("baz"));
+        assert!(string.contains_(&'f'));
+        assert!(!string.contains_(&'z'));
+    }
+
+    #[test]
+    fn test_contains_any_() {
+        assert!([1, 2, 3].contains_any_(&[4, 3]));
+        assert!(![1, 2, 3].contains_any_(&[4, 5]));
+        assert!(![1, 2, 3].contains_any_(&[0; 0]));
+        assert!("foobar".contains_any_(["baz", "bar"]));
+    }
+
+    #[test]
+    fn test_contains_all_() {
+        assert!([1, 2, 3].contains_all_(&[3, 1]));
+        assert!(![1, 2, 3].contains_all_(&[3, 4]));
+        assert!([1, 2, 3].contains_all_(&[0; 0]));
+    }
+
+    #[test]
+    fn test_contains_none_() {
+        assert!([1, 2, 3].contains_none_(&[4, 5]));
+        assert!(![1, 2, 3].contains_none_(&[4, 3]));
+        assert!([1, 2, 3].contains_none_(&[0; 0]));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_hash_set_contains_any_all_none_() {
+        let set_of_strings = HashSet::from(["foo".to_owned(), "bar".to_owned()]);
+        assert!(set_of_strings.contains_any_(["baz", "foo"]));
+        assert!(set_of_strings.contains_all_(["bar", "foo"]));
+        assert!(!set_of_strings.contains_all_(["bar", "baz"]));
+        assert!(set_of_strings.contains_none_(["baz"]));
+
+        let empty = HashSet::<String>::default();
+        assert!(!empty.contains_any_(["foo"]));
+        assert!(!empty.contains_all_(["foo"]));
+        assert!(empty.contains_all_(["foo"; 0]));
+        assert!(empty.contains_none_(["foo"]));
+    }
+
+    #[test]
+    fn test_sorted_slice() {
+        let sorted = SortedSlice::new(&[1, 3, 5, 7]);
+        assert!(sorted.contains_(&5));
+        assert!(!sorted.contains_(&4));
+        assert!(sorted.contains_any_(&[4, 7]));
+        assert!(sorted.contains_all_(&[1, 7]));
+        assert!(sorted.contains_none_(&[0, 2, 8]));
+        assert_eq!(sorted.len(), 4);
+
+        let strings = ["bar".to_owned(), "foo".to_owned()];
+        assert!(SortedSlice::new(&strings).contains_("foo"));
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(debug_assertions)]
+    fn test_sorted_slice_unsorted_panics() {
+        SortedSlice::new(&[2, 1]);
+    }
+
+    #[test]
+    fn test_range() {
+        assert!((1..3).contains_(&2));
+        assert!(!(1..3).contains_(&3));
+        assert!((1..=3).contains_(&3));
+        assert!(!(1..=3).contains_(&4));
+    }
+}
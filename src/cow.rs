@@ -1,6 +1,13 @@
+use alloc::{
+    borrow::{Cow, ToOwned},
+    string::String,
+    vec::Vec,
+};
+use core::{borrow::Borrow, ptr, slice::SliceIndex};
+#[cfg(feature = "std")]
 use std::{
-    borrow::{Borrow, Cow},
-    slice::SliceIndex,
+    ffi::OsStr,
+    path::{self, Component, Path, PathBuf},
 };
 
 pub trait CowExt<'a> {
@@ -10,6 +17,12 @@ pub trait CowExt<'a> {
         self,
         mapper: impl FnOnce(&Self::Borrowed) -> &Self::Borrowed,
     ) -> Cow<'a, Self::Borrowed>;
+    fn map_borrowed_eq(
+        self,
+        mapper: impl FnOnce(&Self::Borrowed) -> &Self::Borrowed,
+    ) -> Cow<'a, Self::Borrowed>
+    where
+        Self::Borrowed: PartialEq;
     fn map_borrowed_ref(
         &self,
         mapper: impl FnOnce(&Self::Borrowed) -> &Self::Borrowed,
@@ -22,15 +35,46 @@ pub trait CowExt<'a> {
         &self,
         mapper: impl FnOnce(&Self::Borrowed) -> Cow<'_, Self::Borrowed>,
     ) -> Cow<'a, Self::Borrowed>;
+    fn map_owned(
+        self,
+        mapper: impl FnOnce(<Self::Borrowed as ToOwned>::Owned) -> <Self::Borrowed as ToOwned>::Owned,
+    ) -> Cow<'a, Self::Borrowed>;
+    fn modify(
+        self,
+        modifier: impl FnOnce(&mut <Self::Borrowed as ToOwned>::Owned),
+    ) -> Cow<'a, Self::Borrowed>;
+    fn map_into<TTarget>(self) -> Cow<'a, TTarget>
+    where
+        TTarget: 'a + ToOwned + ?Sized,
+        Self::Borrowed: AsRef<TTarget>,
+        <Self::Borrowed as ToOwned>::Owned: Into<TTarget::Owned>;
 }
 
 impl<'a, TBorrowed> CowExt<'a> for Cow<'a, TBorrowed>
 where
-    TBorrowed: 'a + ToOwned + ?Sized + PartialEq,
+    TBorrowed: 'a + ToOwned + ?Sized,
 {
     type Borrowed = TBorrowed;
 
     fn map_borrowed(self, mapper: impl FnOnce(&TBorrowed) -> &TBorrowed) -> Cow<'a, TBorrowed> {
+        match self {
+            Cow::Borrowed(value) => Cow::Borrowed(mapper(value)),
+            Cow::Owned(value) => {
+                let value_ref = value.borrow();
+                let mapped = mapper(value_ref);
+                Cow::Owned(if ptr::eq(value_ref, mapped) {
+                    value
+                } else {
+                    mapped.to_owned()
+                })
+            }
+        }
+    }
+
+    fn map_borrowed_eq(self, mapper: impl FnOnce(&TBorrowed) -> &TBorrowed) -> Cow<'a, TBorrowed>
+    where
+        TBorrowed: PartialEq,
+    {
         match self {
             Cow::Borrowed(value) => Cow::Borrowed(mapper(value)),
             Cow::Owned(value) => {
@@ -63,7 +107,7 @@ where
                 Cow::Owned(match mapper(value_ref) {
                     Cow::Owned(mapped) => mapped,
                     Cow::Borrowed(mapped) => {
-                        if value_ref == mapped {
+                        if ptr::eq(value_ref, mapped) {
                             value
                         } else {
                             mapped.to_owned()
@@ -83,6 +127,31 @@ where
             Cow::Owned(value) => Cow::Owned(mapper(value.borrow()).into_owned()),
         }
     }
+
+    fn map_owned(
+        self,
+        mapper: impl FnOnce(TBorrowed::Owned) -> TBorrowed::Owned,
+    ) -> Cow<'a, TBorrowed> {
+        Cow::Owned(mapper(self.into_owned()))
+    }
+
+    fn modify(self, modifier: impl FnOnce(&mut TBorrowed::Owned)) -> Cow<'a, TBorrowed> {
+        let mut owned = self.into_owned();
+        modifier(&mut owned);
+        Cow::Owned(owned)
+    }
+
+    fn map_into<TTarget>(self) -> Cow<'a, TTarget>
+    where
+        TTarget: 'a + ToOwned + ?Sized,
+        TBorrowed: AsRef<TTarget>,
+        TBorrowed::Owned: Into<TTarget::Owned>,
+    {
+        match self {
+            Cow::Borrowed(value) => Cow::Borrowed(value.as_ref()),
+            Cow::Owned(value) => Cow::Owned(value.into()),
+        }
+    }
 }
 
 pub trait CowStrExt<'a> {
@@ -96,6 +165,45 @@ pub trait CowStrExt<'a> {
     ) -> Cow<'a, str>;
     fn trimmed(self) -> Cow<'a, str>;
     fn trimmed_ref(&self) -> Cow<'a, str>;
+    fn trimmed_start(self) -> Cow<'a, str>;
+    fn trimmed_end(self) -> Cow<'a, str>;
+    fn trimmed_matches(self, predicate: impl FnMut(char) -> bool) -> Cow<'a, str>;
+    fn stripped_prefix(self, prefix: &str) -> Cow<'a, str>;
+    fn stripped_suffix(self, suffix: &str) -> Cow<'a, str>;
+    fn replaced(self, from: &str, to: &str) -> Cow<'a, str>;
+    fn replacedn(self, from: &str, to: &str, count: usize) -> Cow<'a, str>;
+    fn to_lowercase_cow(self) -> Cow<'a, str>;
+    fn to_uppercase_cow(self) -> Cow<'a, str>;
+    fn to_ascii_lowercase_cow(self) -> Cow<'a, str>;
+    fn to_ascii_uppercase_cow(self) -> Cow<'a, str>;
+    fn collapse_whitespace(self) -> Cow<'a, str>;
+    fn escape_debug_cow(self) -> Cow<'a, str>;
+    fn escape_default_cow(self) -> Cow<'a, str>;
+    fn escape_unicode_cow(self) -> Cow<'a, str>;
+    fn split_cow(&self, separator: &str) -> Vec<Cow<'a, str>>;
+    fn zip_cow(self, other: Cow<'a, str>) -> Cow<'a, str>;
+}
+
+fn owned_if<'b>(
+    value: &'b str,
+    changed: bool,
+    to_owned: impl FnOnce(&str) -> String,
+) -> Cow<'b, str> {
+    if changed {
+        Cow::Owned(to_owned(value))
+    } else {
+        Cow::Borrowed(value)
+    }
+}
+
+fn has_whitespace_to_collapse(value: &str) -> bool {
+    let mut previous_was_whitespace = false;
+    value.chars().any(|c| {
+        let is_whitespace = c.is_whitespace();
+        let collapse = is_whitespace && (c != ' ' || previous_was_whitespace);
+        previous_was_whitespace = is_whitespace;
+        collapse
+    })
 }
 
 impl<'a> CowStrExt<'a> for Cow<'a, str> {
@@ -122,6 +230,425 @@ impl<'a> CowStrExt<'a> for Cow<'a, str> {
     fn trimmed_ref(&self) -> Cow<'a, str> {
         self.map_borrowed_ref(str::trim)
     }
+
+    fn trimmed_start(self) -> Cow<'a, str> {
+        self.map_borrowed(str::trim_start)
+    }
+
+    fn trimmed_end(self) -> Cow<'a, str> {
+        self.map_borrowed(str::trim_end)
+    }
+
+    fn trimmed_matches(self, predicate: impl FnMut(char) -> bool) -> Cow<'a, str> {
+        self.map_borrowed(|value| value.trim_matches(predicate))
+    }
+
+    fn stripped_prefix(self, prefix: &str) -> Cow<'a, str> {
+        self.map_borrowed(|value| value.strip_prefix(prefix).unwrap_or(value))
+    }
+
+    fn stripped_suffix(self, suffix: &str) -> Cow<'a, str> {
+        self.map_borrowed(|value| value.strip_suffix(suffix).unwrap_or(value))
+    }
+
+    fn replaced(self, from: &str, to: &str) -> Cow<'a, str> {
+        self.map_cow(|value| owned_if(value, value.contains(from), |value| value.replace(from, to)))
+    }
+
+    fn replacedn(self, from: &str, to: &str, count: usize) -> Cow<'a, str> {
+        self.map_cow(|value| {
+            owned_if(value, count > 0 && value.contains(from), |value| {
+                value.replacen(from, to, count)
+            })
+        })
+    }
+
+    fn to_lowercase_cow(self) -> Cow<'a, str> {
+        self.map_cow(|value| {
+            owned_if(
+                value,
+                !value.chars().flat_map(char::to_lowercase).eq(value.chars()),
+                str::to_lowercase,
+            )
+        })
+    }
+
+    fn to_uppercase_cow(self) -> Cow<'a, str> {
+        self.map_cow(|value| {
+            owned_if(
+                value,
+                !value.chars().flat_map(char::to_uppercase).eq(value.chars()),
+                str::to_uppercase,
+            )
+        })
+    }
+
+    fn to_ascii_lowercase_cow(self) -> Cow<'a, str> {
+        self.map_cow(|value| {
+            owned_if(
+                value,
+                value.bytes().any(|byte| byte.is_ascii_uppercase()),
+                str::to_ascii_lowercase,
+            )
+        })
+    }
+
+    fn to_ascii_uppercase_cow(self) -> Cow<'a, str> {
+        self.map_cow(|value| {
+            owned_if(
+                value,
+                value.bytes().any(|byte| byte.is_ascii_lowercase()),
+                str::to_ascii_uppercase,
+            )
+        })
+    }
+
+    fn collapse_whitespace(self) -> Cow<'a, str> {
+        self.map_cow(|value| {
+            owned_if(value, has_whitespace_to_collapse(value), |value| {
+                let mut collapsed = String::with_capacity(value.len());
+                let mut previous_was_whitespace = false;
+                for c in value.chars() {
+                    let is_whitespace = c.is_whitespace();
+                    if !is_whitespace {
+                        collapsed.push(c);
+                    } else if !previous_was_whitespace {
+                        collapsed.push(' ');
+                    }
+                    previous_was_whitespace = is_whitespace;
+                }
+                collapsed
+            })
+        })
+    }
+
+    fn escape_debug_cow(self) -> Cow<'a, str> {
+        self.map_cow(|value| {
+            owned_if(value, !value.escape_debug().eq(value.chars()), |value| {
+                value.escape_debug().collect()
+            })
+        })
+    }
+
+    fn escape_default_cow(self) -> Cow<'a, str> {
+        self.map_cow(|value| {
+            owned_if(value, !value.escape_default().eq(value.chars()), |value| {
+                value.escape_default().collect()
+            })
+        })
+    }
+
+    fn escape_unicode_cow(self) -> Cow<'a, str> {
+        self.map_cow(|value| {
+            owned_if(value, !value.is_empty(), |value| {
+                value.escape_unicode().collect()
+            })
+        })
+    }
+
+    fn split_cow(&self, separator: &str) -> Vec<Cow<'a, str>> {
+        match self {
+            Cow::Borrowed(value) => value.split(separator).map(Cow::Borrowed).collect(),
+            Cow::Owned(value) => value
+                .split(separator)
+                .map(|piece| Cow::Owned(piece.to_owned()))
+                .collect(),
+        }
+    }
+
+    fn zip_cow(self, other: Cow<'a, str>) -> Cow<'a, str> {
+        if other.is_empty() {
+            self
+        } else if self.is_empty() {
+            other
+        } else {
+            let mut zipped = self.into_owned();
+            zipped.push_str(&other);
+            Cow::Owned(zipped)
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+pub trait CowPathExt<'a> {
+    fn joined(self, path: impl AsRef<Path>) -> Cow<'a, Path>;
+    fn with_extension_cow(self, extension: impl AsRef<OsStr>) -> Cow<'a, Path>;
+    fn stripped_prefix(self, base: impl AsRef<Path>) -> Cow<'a, Path>;
+    fn normalized(self) -> Cow<'a, Path>;
+    fn to_str_lossy_cow(self) -> Cow<'a, str>;
+    fn file_name_cow(self) -> Option<Cow<'a, OsStr>>;
+}
+
+#[cfg(feature = "std")]
+fn is_normalized(path: &Path) -> bool {
+    let bytes = path.as_os_str().as_encoded_bytes();
+    let mut position = 0;
+    let mut needs_separator = false;
+    for component in path.components() {
+        match component {
+            Component::Prefix(_) => return false,
+            Component::CurDir => continue,
+            _ => {}
+        }
+        if needs_separator {
+            let separator = path::MAIN_SEPARATOR_STR.as_bytes();
+            if !bytes[position..].starts_with(separator) {
+                return false;
+            }
+            position += separator.len();
+        }
+        let component_bytes = component.as_os_str().as_encoded_bytes();
+        if !bytes[position..].starts_with(component_bytes) {
+            return false;
+        }
+        position += component_bytes.len();
+        needs_separator = !matches!(component, Component::RootDir);
+    }
+    position == bytes.len() || (position == 0 && bytes == b".")
+}
+
+#[cfg(feature = "std")]
+impl<'a> CowPathExt<'a> for Cow<'a, Path> {
+    fn joined(self, path: impl AsRef<Path>) -> Cow<'a, Path> {
+        let path = path.as_ref();
+        let ends_with_separator = self
+            .as_os_str()
+            .as_encoded_bytes()
+            .last()
+            .is_none_or(|&byte| path::is_separator(byte.into()));
+        if path.as_os_str().is_empty() && ends_with_separator {
+            self
+        } else {
+            self.modify(|value| value.push(path))
+        }
+    }
+
+    fn with_extension_cow(self, extension: impl AsRef<OsStr>) -> Cow<'a, Path> {
+        let extension = extension.as_ref();
+        let unchanged = match self.file_stem() {
+            None => true,
+            Some(file_stem) => {
+                let bytes = self.as_os_str().as_encoded_bytes();
+                let file_stem = file_stem.as_encoded_bytes();
+                let stem_end =
+                    file_stem.as_ptr() as usize - bytes.as_ptr() as usize + file_stem.len();
+                match bytes[stem_end..].split_first() {
+                    None => extension.is_empty(),
+                    Some((&b'.', current)) => {
+                        !extension.is_empty() && current == extension.as_encoded_bytes()
+                    }
+                    Some(_) => false,
+                }
+            }
+        };
+        if unchanged {
+            self
+        } else {
+            self.modify(|value| {
+                value.set_extension(extension);
+            })
+        }
+    }
+
+    fn stripped_prefix(self, base: impl AsRef<Path>) -> Cow<'a, Path> {
+        self.map_borrowed(|value| value.strip_prefix(base).unwrap_or(value))
+    }
+
+    fn normalized(self) -> Cow<'a, Path> {
+        if is_normalized(&self) {
+            return self;
+        }
+        self.map_cow(|value| {
+            let mut normalized = value
+                .components()
+                .filter(|component| !matches!(component, Component::CurDir))
+                .collect::<PathBuf>();
+            if normalized.as_os_str().is_empty() && !value.as_os_str().is_empty() {
+                normalized.push(Component::CurDir);
+            }
+            if normalized.as_os_str() == value.as_os_str() {
+                Cow::Borrowed(value)
+            } else {
+                Cow::Owned(normalized)
+            }
+        })
+    }
+
+    fn to_str_lossy_cow(self) -> Cow<'a, str> {
+        self.map_into::<OsStr>().to_str_lossy_cow()
+    }
+
+    fn file_name_cow(self) -> Option<Cow<'a, OsStr>> {
+        match self {
+            Cow::Borrowed(value) => value.file_name().map(Cow::Borrowed),
+            Cow::Owned(value) => value
+                .file_name()
+                .map(|file_name| Cow::Owned(file_name.to_owned())),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+pub trait CowOsStrExt<'a> {
+    fn to_str_lossy_cow(self) -> Cow<'a, str>;
+    fn to_ascii_lowercase_cow(self) -> Cow<'a, OsStr>;
+    fn to_ascii_uppercase_cow(self) -> Cow<'a, OsStr>;
+}
+
+#[cfg(feature = "std")]
+impl<'a> CowOsStrExt<'a> for Cow<'a, OsStr> {
+    fn to_str_lossy_cow(self) -> Cow<'a, str> {
+        match self {
+            Cow::Borrowed(value) => value.to_string_lossy(),
+            Cow::Owned(value) => Cow::Owned(
+                value
+                    .into_string()
+                    .unwrap_or_else(|value| value.to_string_lossy().into_owned()),
+            ),
+        }
+    }
+
+    fn to_ascii_lowercase_cow(self) -> Cow<'a, OsStr> {
+        if self.as_encoded_bytes().iter().any(u8::is_ascii_uppercase) {
+            self.modify(|value| value.make_ascii_lowercase())
+        } else {
+            self
+        }
+    }
+
+    fn to_ascii_uppercase_cow(self) -> Cow<'a, OsStr> {
+        if self.as_encoded_bytes().iter().any(u8::is_ascii_lowercase) {
+            self.modify(|value| value.make_ascii_uppercase())
+        } else {
+            self
+        }
+    }
+}
+
+pub trait CowSliceExt<'a> {
+    type Item: Clone;
+
+    fn sliced<TRange: SliceIndex<[Self::Item], Output = [Self::Item]>>(
+        &self,
+        get_range: impl FnOnce(usize) -> TRange,
+    ) -> Cow<'a, [Self::Item]>;
+    fn filtered(self, predicate: impl FnMut(&Self::Item) -> bool) -> Cow<'a, [Self::Item]>;
+    fn deduped(self) -> Cow<'a, [Self::Item]>
+    where
+        Self::Item: PartialEq;
+    fn sorted_cow(self) -> Cow<'a, [Self::Item]>
+    where
+        Self::Item: Ord;
+    fn pushed(self, item: Self::Item) -> Cow<'a, [Self::Item]>;
+    fn extended(self, items: impl IntoIterator<Item = Self::Item>) -> Cow<'a, [Self::Item]>;
+    fn into_owned_vec(self) -> Vec<Self::Item>;
+    fn zip_cow(self, other: Cow<'a, [Self::Item]>) -> Cow<'a, [Self::Item]>;
+}
+
+impl<'a, TItem: Clone> CowSliceExt<'a> for Cow<'a, [TItem]> {
+    type Item = TItem;
+
+    fn sliced<TRange: SliceIndex<[TItem], Output = [TItem]>>(
+        &self,
+        get_range: impl FnOnce(usize) -> TRange,
+    ) -> Cow<'a, [TItem]> {
+        let range = get_range(self.len());
+        match self {
+            Cow::Borrowed(value) => Cow::Borrowed(&value[range]),
+            Cow::Owned(value) => Cow::Owned(value[range].to_vec()),
+        }
+    }
+
+    fn filtered(self, mut predicate: impl FnMut(&TItem) -> bool) -> Cow<'a, [TItem]> {
+        let slice = match self {
+            Cow::Borrowed(slice) => slice,
+            Cow::Owned(mut vec) => {
+                vec.retain(predicate);
+                return Cow::Owned(vec);
+            }
+        };
+        let mut kept_start = None;
+        for (index, item) in slice.iter().enumerate() {
+            match (kept_start, predicate(item)) {
+                (None, true) => kept_start = Some(index),
+                (Some(start), false) => {
+                    let rest = &slice[index + 1..];
+                    let Some(next_kept) = rest.iter().position(&mut predicate) else {
+                        return Cow::Borrowed(&slice[start..index]);
+                    };
+                    let mut filtered = slice[start..index].to_vec();
+                    filtered.push(rest[next_kept].clone());
+                    filtered.extend(
+                        rest[next_kept + 1..]
+                            .iter()
+                            .filter(|item| predicate(item))
+                            .cloned(),
+                    );
+                    return Cow::Owned(filtered);
+                }
+                _ => {}
+            }
+        }
+        Cow::Borrowed(kept_start.map_or(&slice[..0], |start| &slice[start..]))
+    }
+
+    fn deduped(self) -> Cow<'a, [TItem]>
+    where
+        TItem: PartialEq,
+    {
+        if self.windows(2).any(|pair| pair[0] == pair[1]) {
+            let mut vec = self.into_owned();
+            vec.dedup();
+            Cow::Owned(vec)
+        } else {
+            self
+        }
+    }
+
+    fn sorted_cow(self) -> Cow<'a, [TItem]>
+    where
+        TItem: Ord,
+    {
+        if self.is_sorted() {
+            self
+        } else {
+            let mut vec = self.into_owned();
+            vec.sort();
+            Cow::Owned(vec)
+        }
+    }
+
+    fn pushed(self, item: TItem) -> Cow<'a, [TItem]> {
+        let mut vec = self.into_owned();
+        vec.push(item);
+        Cow::Owned(vec)
+    }
+
+    fn extended(self, items: impl IntoIterator<Item = TItem>) -> Cow<'a, [TItem]> {
+        let mut items = items.into_iter().peekable();
+        if items.peek().is_none() {
+            return self;
+        }
+        let mut vec = self.into_owned();
+        vec.extend(items);
+        Cow::Owned(vec)
+    }
+
+    fn into_owned_vec(self) -> Vec<TItem> {
+        self.into_owned()
+    }
+
+    fn zip_cow(self, other: Cow<'a, [TItem]>) -> Cow<'a, [TItem]> {
+        if other.is_empty() {
+            self
+        } else if self.is_empty() {
+            other
+        } else {
+            let mut zipped = self.into_owned();
+            zipped.extend_from_slice(&other);
+            Cow::Owned(zipped)
+        }
+    }
 }
 
 pub trait IntoCow<'a, T>
@@ -172,6 +699,404 @@ mod tests {
         assert_eq!(IntoCow::<str>::into_cow(str), Cow::Borrowed("foo"));
     }
 
+    #[test]
+    fn test_trimmed_variants() {
+        assert_eq!(Cow::Borrowed("  foo  ").trimmed_start(), "foo  ");
+        assert_eq!(Cow::Borrowed("  foo  ").trimmed_end(), "  foo");
+        let trimmed = Cow::Borrowed("xxfooxx").trimmed_matches(|c| c == 'x');
+        assert_eq!(trimmed, "foo");
+        assert!(matches!(trimmed, Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_stripped_prefix_suffix() {
+        let stripped = Cow::Borrowed("foobar").stripped_prefix("foo");
+        assert_eq!(stripped, "bar");
+        assert!(matches!(stripped, Cow::Borrowed(_)));
+        assert_eq!(Cow::Borrowed("foobar").stripped_prefix("baz"), "foobar");
+        assert_eq!(
+            Cow::<str>::Owned("foobar".to_owned()).stripped_suffix("bar"),
+            "foo"
+        );
+    }
+
+    #[test]
+    fn test_replaced() {
+        let unchanged = Cow::Borrowed("foo").replaced("x", "y");
+        assert!(matches!(unchanged, Cow::Borrowed(_)));
+        let replaced = Cow::Borrowed("foo").replaced("o", "0");
+        assert_eq!(replaced, "f00");
+        assert!(matches!(replaced, Cow::Owned(_)));
+        assert_eq!(Cow::Borrowed("foo").replacedn("o", "0", 1), "f0o");
+        assert!(matches!(
+            Cow::Borrowed("foo").replacedn("o", "0", 0),
+            Cow::Borrowed(_)
+        ));
+    }
+
+    #[test]
+    fn test_case_conversions() {
+        assert!(matches!(
+            Cow::Borrowed("foo bar").to_lowercase_cow(),
+            Cow::Borrowed(_)
+        ));
+        assert_eq!(Cow::Borrowed("Foo ÉTÉ").to_lowercase_cow(), "foo été");
+        assert!(matches!(
+            Cow::Borrowed("FOO 1").to_uppercase_cow(),
+            Cow::Borrowed(_)
+        ));
+        assert_eq!(Cow::Borrowed("straße").to_uppercase_cow(), "STRASSE");
+        assert!(matches!(
+            Cow::Borrowed("foo é").to_ascii_lowercase_cow(),
+            Cow::Borrowed(_)
+        ));
+        assert_eq!(Cow::Borrowed("FOO É").to_ascii_lowercase_cow(), "foo É");
+        assert_eq!(Cow::Borrowed("foo é").to_ascii_uppercase_cow(), "FOO é");
+    }
+
+    #[test]
+    fn test_collapse_whitespace() {
+        assert!(matches!(
+            Cow::Borrowed("foo bar baz").collapse_whitespace(),
+            Cow::Borrowed(_)
+        ));
+        assert_eq!(
+            Cow::Borrowed(" foo \t\n bar\tbaz").collapse_whitespace(),
+            " foo bar baz"
+        );
+    }
+
+    #[test]
+    fn test_escape() {
+        assert!(matches!(
+            Cow::Borrowed("foo").escape_debug_cow(),
+            Cow::Borrowed(_)
+        ));
+        assert_eq!(Cow::Borrowed("foo\n").escape_debug_cow(), "foo\\n");
+        assert!(matches!(
+            Cow::Borrowed("foo").escape_default_cow(),
+            Cow::Borrowed(_)
+        ));
+        assert_eq!(Cow::Borrowed("é").escape_default_cow(), "\\u{e9}");
+        assert_eq!(Cow::Borrowed("a").escape_unicode_cow(), "\\u{61}");
+        assert!(matches!(
+            Cow::Borrowed("").escape_unicode_cow(),
+            Cow::Borrowed(_)
+        ));
+    }
+
+    #[test]
+    fn test_split_cow() {
+        let pieces = Cow::Borrowed("foo,bar").split_cow(",");
+        assert_eq!(pieces, vec!["foo", "bar"]);
+        assert!(pieces.iter().all(|piece| matches!(piece, Cow::Borrowed(_))));
+
+        let pieces = Cow::<str>::Owned("foo,bar".to_owned()).split_cow(",");
+        assert_eq!(pieces, vec!["foo", "bar"]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_path_joined() {
+        let base = Cow::Borrowed(Path::new("foo/"));
+        assert!(matches!(base.clone().joined(""), Cow::Borrowed(_)));
+        assert_eq!(base.joined("bar"), Path::new("foo/bar"));
+        assert_eq!(
+            Cow::Borrowed(Path::new("foo")).joined(""),
+            Path::new("foo/")
+        );
+
+        let owned: Cow<'_, Path> = Cow::Owned("foo".into());
+        assert_eq!(owned.joined("/bar"), Path::new("/bar"));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_path_with_extension_cow() {
+        let path = Cow::Borrowed(Path::new("foo.tar.gz"));
+        assert!(matches!(
+            path.clone().with_extension_cow("gz"),
+            Cow::Borrowed(_)
+        ));
+        assert_eq!(path.with_extension_cow("zip"), Path::new("foo.tar.zip"));
+        assert!(matches!(
+            Cow::Borrowed(Path::new("foo")).with_extension_cow(""),
+            Cow::Borrowed(_)
+        ));
+        assert_eq!(
+            Cow::Borrowed(Path::new("foo.")).with_extension_cow(""),
+            Path::new("foo")
+        );
+        assert!(matches!(
+            Cow::Borrowed(Path::new("/")).with_extension_cow("txt"),
+            Cow::Borrowed(_)
+        ));
+        for (path, extension) in [("foo/", ""), ("foo/.", ""), ("foo.gz/", "gz")] {
+            assert_eq!(
+                Cow::Borrowed(Path::new(path)).with_extension_cow(extension),
+                Path::new(path).with_extension(extension)
+            );
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_path_stripped_prefix() {
+        let path = Cow::Borrowed(Path::new("foo/bar"));
+        assert!(matches!(
+            path.clone().stripped_prefix("foo"),
+            Cow::Borrowed(stripped) if stripped == Path::new("bar")
+        ));
+        assert!(matches!(
+            path.stripped_prefix("baz"),
+            Cow::Borrowed(unchanged) if unchanged == Path::new("foo/bar")
+        ));
+
+        let owned: Cow<'_, Path> = Cow::Owned("foo/bar".into());
+        let original = owned.as_os_str().as_encoded_bytes().as_ptr();
+        let unchanged = owned.stripped_prefix("baz");
+        assert_eq!(unchanged.as_os_str().as_encoded_bytes().as_ptr(), original);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_path_normalized() {
+        for canonical in ["", ".", "foo/bar", "/foo/../bar", "../foo"] {
+            assert!(matches!(
+                Cow::Borrowed(Path::new(canonical)).normalized(),
+                Cow::Borrowed(_)
+            ));
+        }
+        assert_eq!(
+            Cow::Borrowed(Path::new("./foo//./bar/")).normalized(),
+            Path::new("foo/bar")
+        );
+        assert_eq!(Cow::Borrowed(Path::new("./")).normalized(), Path::new("."));
+        let owned: Cow<'_, Path> = Cow::Owned("/foo/./bar".into());
+        assert_eq!(owned.normalized(), Path::new("/foo/bar"));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_is_normalized() {
+        for normalized in ["", ".", "foo", "/", "/foo/bar", "../foo", "foo/.."] {
+            assert!(is_normalized(Path::new(normalized)), "{normalized:?}");
+        }
+        for unnormalized in [
+            "./",
+            "./foo",
+            "foo/",
+            "foo//bar",
+            "foo/./bar",
+            "//foo",
+            "foo/.",
+        ] {
+            assert!(!is_normalized(Path::new(unnormalized)), "{unnormalized:?}");
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_path_to_str_lossy_cow() {
+        assert!(matches!(
+            Cow::Borrowed(Path::new("foo")).to_str_lossy_cow(),
+            Cow::Borrowed("foo")
+        ));
+        let owned: Cow<'_, Path> = Cow::Owned("foo".into());
+        assert_eq!(owned.to_str_lossy_cow(), "foo");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_path_file_name_cow() {
+        assert!(matches!(
+            Cow::Borrowed(Path::new("foo/bar.txt")).file_name_cow(),
+            Some(Cow::Borrowed(file_name)) if file_name == "bar.txt"
+        ));
+        assert_eq!(Cow::Borrowed(Path::new("/")).file_name_cow(), None);
+        let owned: Cow<'_, Path> = Cow::Owned("foo/bar.txt".into());
+        assert_eq!(owned.file_name_cow().unwrap(), OsStr::new("bar.txt"));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_os_str_ascii_case() {
+        let lower = Cow::Borrowed(OsStr::new("foo"));
+        assert!(matches!(
+            lower.clone().to_ascii_lowercase_cow(),
+            Cow::Borrowed(_)
+        ));
+        assert_eq!(lower.to_ascii_uppercase_cow(), OsStr::new("FOO"));
+        assert_eq!(
+            Cow::Borrowed(OsStr::new("FoO")).to_ascii_lowercase_cow(),
+            OsStr::new("foo")
+        );
+    }
+
+    #[test]
+    fn test_slice_sliced() {
+        let cow: Cow<'_, [i32]> = Cow::Borrowed(&[1, 2, 3]);
+        assert!(matches!(cow.sliced(|len| 1..len), Cow::Borrowed(&[2, 3])));
+        let cow: Cow<'_, [i32]> = Cow::Owned(vec![1, 2, 3]);
+        assert_eq!(cow.sliced(|_| ..1), Cow::<[i32]>::Owned(vec![1]));
+    }
+
+    #[test]
+    fn test_slice_filtered() {
+        let slice = [1, 2, 3, 4];
+        let cow = Cow::Borrowed(&slice[..]);
+        assert!(matches!(
+            cow.clone().filtered(|_| true),
+            Cow::Borrowed(&[1, 2, 3, 4])
+        ));
+        assert!(matches!(
+            cow.clone().filtered(|&item| item > 2),
+            Cow::Borrowed(&[3, 4])
+        ));
+        assert!(matches!(
+            cow.clone().filtered(|&item| item < 3),
+            Cow::Borrowed(&[1, 2])
+        ));
+        assert!(matches!(
+            cow.clone().filtered(|&item| item == 2 || item == 3),
+            Cow::Borrowed(&[2, 3])
+        ));
+        assert!(matches!(
+            cow.clone().filtered(|_| false),
+            Cow::Borrowed(&[])
+        ));
+        let filtered = cow.filtered(|&item| item != 2);
+        assert!(matches!(filtered, Cow::Owned(_)));
+        assert_eq!(*filtered, [1, 3, 4]);
+
+        let cow: Cow<'_, [i32]> = Cow::Owned(vec![1, 2, 3]);
+        assert_eq!(*cow.filtered(|&item| item != 2), [1, 3]);
+    }
+
+    #[test]
+    fn test_slice_deduped() {
+        let cow: Cow<'_, [i32]> = Cow::Borrowed(&[1, 2, 1]);
+        assert!(matches!(cow.deduped(), Cow::Borrowed(_)));
+        let cow: Cow<'_, [i32]> = Cow::Borrowed(&[1, 1, 2]);
+        let deduped = cow.deduped();
+        assert!(matches!(deduped, Cow::Owned(_)));
+        assert_eq!(*deduped, [1, 2]);
+    }
+
+    #[test]
+    fn test_slice_sorted_cow() {
+        let cow: Cow<'_, [i32]> = Cow::Borrowed(&[1, 2, 2]);
+        assert!(matches!(cow.sorted_cow(), Cow::Borrowed(_)));
+        let cow: Cow<'_, [i32]> = Cow::Borrowed(&[2, 1]);
+        let sorted = cow.sorted_cow();
+        assert!(matches!(sorted, Cow::Owned(_)));
+        assert_eq!(*sorted, [1, 2]);
+    }
+
+    #[test]
+    fn test_slice_pushed_extended() {
+        let cow: Cow<'_, [i32]> = Cow::Borrowed(&[1]);
+        assert!(matches!(cow.clone().extended([]), Cow::Borrowed(_)));
+        let extended = cow.clone().extended([2, 3]);
+        assert!(matches!(extended, Cow::Owned(_)));
+        assert_eq!(*extended, [1, 2, 3]);
+        assert_eq!(cow.pushed(2).into_owned_vec(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_map_borrowed_identity() {
+        let owned: Cow<'_, str> = Cow::Owned("foo".to_owned());
+        let original = owned.as_ptr();
+        let mapped = owned.map_borrowed(|value| value);
+        assert_eq!(mapped.as_ptr(), original);
+
+        let owned: Cow<'_, str> = Cow::Owned("foo".to_owned());
+        let mapped = owned.map_borrowed(|_| "foo");
+        assert!(matches!(mapped, Cow::Owned(_)));
+        assert_ne!(mapped.as_ptr(), "foo".as_ptr());
+        assert_eq!(mapped, "foo");
+
+        let owned: Cow<'_, str> = Cow::Owned("foo".to_owned());
+        assert_eq!(owned.map_borrowed(|value| &value[..2]), "fo");
+
+        let path: Cow<'_, std::path::Path> = Cow::Owned("foo/bar".into());
+        assert_eq!(
+            path.map_borrowed(|value| value.parent().unwrap()),
+            std::path::Path::new("foo")
+        );
+    }
+
+    #[test]
+    fn test_map_borrowed_eq() {
+        let owned: Cow<'_, str> = Cow::Owned("foo".to_owned());
+        let original = owned.as_ptr();
+        let mapped = owned.map_borrowed_eq(|_| "foo");
+        assert_eq!(mapped.as_ptr(), original);
+
+        let borrowed: Cow<'_, str> = Cow::Borrowed("foo");
+        assert!(matches!(
+            borrowed.map_borrowed_eq(|value| &value[1..]),
+            Cow::Borrowed("oo")
+        ));
+    }
+
+    #[test]
+    fn test_map_cow_identity() {
+        let owned: Cow<'_, str> = Cow::Owned(" foo".to_owned());
+        let original = owned.as_ptr();
+        let mapped = owned.map_cow(|value| Cow::Borrowed(value));
+        assert_eq!(mapped.as_ptr(), original);
+    }
+
+    #[test]
+    fn test_map_owned() {
+        let cow = Cow::Borrowed("foo").map_owned(|owned| owned + "bar");
+        assert!(matches!(cow, Cow::Owned(_)));
+        assert_eq!(cow, "foobar");
+    }
+
+    #[test]
+    fn test_modify() {
+        let cow: Cow<'_, [i32]> = Cow::Borrowed(&[1, 2]);
+        assert_eq!(*cow.modify(|vec| vec.push(3)), [1, 2, 3]);
+        let cow: Cow<'_, str> = Cow::Owned("foo".to_owned());
+        assert_eq!(cow.modify(|string| string.make_ascii_uppercase()), "FOO");
+    }
+
+    #[test]
+    fn test_map_into() {
+        let bytes: Cow<'_, [u8]> = Cow::Borrowed("foo").map_into();
+        assert!(matches!(bytes, Cow::Borrowed(b"foo")));
+        let bytes: Cow<'_, [u8]> = Cow::<str>::Owned("foo".to_owned()).map_into();
+        assert!(matches!(bytes, Cow::Owned(_)));
+        assert_eq!(*bytes, *b"foo");
+
+        let path = std::path::Path::new("foo/bar");
+        let os_str: Cow<'_, std::ffi::OsStr> = Cow::Borrowed(path).map_into();
+        assert!(matches!(os_str, Cow::Borrowed(_)));
+        assert_eq!(os_str, std::ffi::OsStr::new("foo/bar"));
+    }
+
+    #[test]
+    fn test_zip_cow() {
+        assert!(matches!(
+            Cow::Borrowed("foo").zip_cow(Cow::Borrowed("")),
+            Cow::Borrowed("foo")
+        ));
+        assert!(matches!(
+            Cow::Borrowed("").zip_cow(Cow::Borrowed("bar")),
+            Cow::Borrowed("bar")
+        ));
+        assert_eq!(Cow::Borrowed("foo").zip_cow(Cow::Borrowed("bar")), "foobar");
+
+        let first: Cow<'_, [i32]> = Cow::Borrowed(&[1]);
+        assert!(matches!(
+            first.clone().zip_cow(Cow::Borrowed(&[])),
+            Cow::Borrowed(&[1])
+        ));
+        assert_eq!(*first.zip_cow(Cow::Owned(vec![2])), [1, 2]);
+    }
+
     #[test]
     fn test_into_cow_slice() {
         let vec = vec!["foo", "bar"];
@@ -1,40 +1,559 @@
-use std::{fmt, vec};
+use std::{collections::VecDeque, fmt, vec};
 
-pub trait IteratorExt {
+pub trait LogSink {
+    fn log(&mut self, message_key: &str, items: &dyn fmt::Debug);
+}
+
+impl<TCallback: FnMut(&str, &dyn fmt::Debug)> LogSink for TCallback {
+    fn log(&mut self, message_key: &str, items: &dyn fmt::Debug) {
+        self(message_key, items)
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default)]
+pub struct StdoutSink;
+
+impl LogSink for StdoutSink {
+    fn log(&mut self, message_key: &str, items: &dyn fmt::Debug) {
+        #[cfg(any(test, feature = "testing"))]
+        crate::testing::print_line(format_args!("{message_key}: {items:?}"));
+        #[cfg(not(any(test, feature = "testing")))]
+        println!("{message_key}: {items:?}");
+    }
+}
+
+#[cfg(feature = "log")]
+#[derive(Copy, Clone, Debug)]
+pub struct LogCrateSink<'a> {
+    pub level: log::Level,
+    pub target: &'a str,
+}
+
+#[cfg(feature = "log")]
+impl<'a> LogCrateSink<'a> {
+    pub fn new(target: &'a str, level: log::Level) -> Self {
+        Self { level, target }
+    }
+}
+
+#[cfg(feature = "log")]
+impl LogSink for LogCrateSink<'_> {
+    fn log(&mut self, message_key: &str, items: &dyn fmt::Debug) {
+        log::log!(target: self.target, self.level, "{message_key}: {items:?}");
+    }
+}
+
+#[cfg(feature = "tracing")]
+#[derive(Copy, Clone, Debug)]
+pub struct TracingSink {
+    pub level: tracing::Level,
+}
+
+#[cfg(feature = "tracing")]
+impl TracingSink {
+    pub fn new(level: tracing::Level) -> Self {
+        Self { level }
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl LogSink for TracingSink {
+    fn log(&mut self, message_key: &str, items: &dyn fmt::Debug) {
+        use tracing::Level;
+
+        match self.level {
+            Level::ERROR => tracing::error!(items = ?items, "{message_key}"),
+            Level::WARN => tracing::warn!(items = ?items, "{message_key}"),
+            Level::INFO => tracing::info!(items = ?items, "{message_key}"),
+            Level::DEBUG => tracing::debug!(items = ?items, "{message_key}"),
+            Level::TRACE => tracing::trace!(items = ?items, "{message_key}"),
+        }
+    }
+}
+
+pub struct InspectLog<'a, TIterator, TSink> {
+    iterator: TIterator,
+    message_key: &'a str,
+    sink: TSink,
+    index: usize,
+}
+
+impl<TIterator, TSink> Iterator for InspectLog<'_, TIterator, TSink>
+where
+    TIterator: Iterator,
+    TIterator::Item: fmt::Debug,
+    TSink: LogSink,
+{
+    type Item = TIterator::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iterator.next()?;
+        self.sink
+            .log(&format!("{}[{}]", self.message_key, self.index), &item);
+        self.index += 1;
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iterator.size_hint()
+    }
+}
+
+pub struct LogTake<'a, TIterator, TSink> {
+    iterator: TIterator,
+    message_key: &'a str,
+    sink: TSink,
+    index: usize,
+    len: usize,
+}
+
+impl<TIterator, TSink> Iterator for LogTake<'_, TIterator, TSink>
+where
+    TIterator: Iterator,
+    TIterator::Item: fmt::Debug,
+    TSink: LogSink,
+{
+    type Item = TIterator::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iterator.next()?;
+        if self.index < self.len {
+            self.sink
+                .log(&format!("{}[{}]", self.message_key, self.index), &item);
+            self.index += 1;
+        }
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iterator.size_hint()
+    }
+}
+
+#[derive(Clone)]
+struct Preformatted(String);
+
+impl fmt::Debug for Preformatted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+struct Summary<'a> {
+    count: usize,
+    first: &'a [Preformatted],
+    last: &'a VecDeque<Preformatted>,
+}
+
+impl fmt::Debug for Summary<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Summary")
+            .field("count", &self.count)
+            .field("first", &self.first)
+            .field("last", &self.last)
+            .finish()
+    }
+}
+
+pub struct LogSummary<'a, TIterator, TSink: LogSink> {
+    iterator: TIterator,
+    message_key: &'a str,
+    sink: TSink,
+    len: usize,
+    count: usize,
+    first: Vec<Preformatted>,
+    last: VecDeque<Preformatted>,
+}
+
+impl<TIterator, TSink> Iterator for LogSummary<'_, TIterator, TSink>
+where
+    TIterator: Iterator,
+    TIterator::Item: fmt::Debug,
+    TSink: LogSink,
+{
+    type Item = TIterator::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iterator.next()?;
+        let formatted = Preformatted(format!("{item:?}"));
+        if self.len > 0 {
+            if self.first.len() < self.len {
+                self.first.push(formatted.clone());
+            }
+            if self.last.len() == self.len {
+                self.last.pop_front();
+            }
+            self.last.push_back(formatted);
+        }
+        self.count += 1;
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iterator.size_hint()
+    }
+}
+
+impl<TIterator, TSink: LogSink> Drop for LogSummary<'_, TIterator, TSink> {
+    fn drop(&mut self) {
+        self.sink.log(
+            self.message_key,
+            &Summary {
+                count: self.count,
+                first: &self.first,
+                last: &self.last,
+            },
+        );
+    }
+}
+
+pub trait IteratorExt: Sized {
     type Item;
 
     fn log(self, message_key: &str) -> vec::IntoIter<Self::Item>;
+    fn log_with(self, message_key: &str, sink: impl LogSink) -> vec::IntoIter<Self::Item>;
+    #[cfg(feature = "log")]
+    fn log_debug(self, target: &str, message_key: &str) -> vec::IntoIter<Self::Item>;
+    #[cfg(feature = "log")]
+    fn log_trace(self, target: &str, message_key: &str) -> vec::IntoIter<Self::Item>;
+    #[cfg(feature = "tracing")]
+    fn log_event(self, message_key: &str, level: tracing::Level) -> vec::IntoIter<Self::Item>;
+
+    fn inspect_log(self, message_key: &str) -> InspectLog<'_, Self, StdoutSink>;
+    fn inspect_log_with<TSink: LogSink>(
+        self,
+        message_key: &str,
+        sink: TSink,
+    ) -> InspectLog<'_, Self, TSink>;
+    fn log_take(self, message_key: &str, len: usize) -> LogTake<'_, Self, StdoutSink>;
+    fn log_take_with<TSink: LogSink>(
+        self,
+        message_key: &str,
+        len: usize,
+        sink: TSink,
+    ) -> LogTake<'_, Self, TSink>;
+    fn log_summary(self, message_key: &str, len: usize) -> LogSummary<'_, Self, StdoutSink>;
+    fn log_summary_with<TSink: LogSink>(
+        self,
+        message_key: &str,
+        len: usize,
+        sink: TSink,
+    ) -> LogSummary<'_, Self, TSink>;
 }
 
 impl<TItem: fmt::Debug, TIterator: Iterator<Item = TItem>> IteratorExt for TIterator {
     type Item = TItem;
 
     fn log(self, message_key: &str) -> vec::IntoIter<TItem> {
+        self.log_with(message_key, StdoutSink)
+    }
+
+    fn log_with(self, message_key: &str, mut sink: impl LogSink) -> vec::IntoIter<TItem> {
         let collected: Vec<_> = self.collect();
-        println!("{message_key}: {collected:?}");
+        sink.log(message_key, &collected);
         collected.into_iter()
     }
+
+    #[cfg(feature = "log")]
+    fn log_debug(self, target: &str, message_key: &str) -> vec::IntoIter<TItem> {
+        self.log_with(message_key, LogCrateSink::new(target, log::Level::Debug))
+    }
+
+    #[cfg(feature = "log")]
+    fn log_trace(self, target: &str, message_key: &str) -> vec::IntoIter<TItem> {
+        self.log_with(message_key, LogCrateSink::new(target, log::Level::Trace))
+    }
+
+    #[cfg(feature = "tracing")]
+    fn log_event(self, message_key: &str, level: tracing::Level) -> vec::IntoIter<TItem> {
+        self.log_with(message_key, TracingSink::new(level))
+    }
+
+    fn inspect_log(self, message_key: &str) -> InspectLog<'_, Self, StdoutSink> {
+        self.inspect_log_with(message_key, StdoutSink)
+    }
+
+    fn inspect_log_with<TSink: LogSink>(
+        self,
+        message_key: &str,
+        sink: TSink,
+    ) -> InspectLog<'_, Self, TSink> {
+        InspectLog {
+            iterator: self,
+            message_key,
+            sink,
+            index: 0,
+        }
+    }
+
+    fn log_take(self, message_key: &str, len: usize) -> LogTake<'_, Self, StdoutSink> {
+        self.log_take_with(message_key, len, StdoutSink)
+    }
+
+    fn log_take_with<TSink: LogSink>(
+        self,
+        message_key: &str,
+        len: usize,
+        sink: TSink,
+    ) -> LogTake<'_, Self, TSink> {
+        LogTake {
+            iterator: self,
+            message_key,
+            sink,
+            index: 0,
+            len,
+        }
+    }
+
+    fn log_summary(self, message_key: &str, len: usize) -> LogSummary<'_, Self, StdoutSink> {
+        self.log_summary_with(message_key, len, StdoutSink)
+    }
+
+    fn log_summary_with<TSink: LogSink>(
+        self,
+        message_key: &str,
+        len: usize,
+        sink: TSink,
+    ) -> LogSummary<'_, Self, TSink> {
+        LogSummary {
+            iterator: self,
+            message_key,
+            sink,
+            len,
+            count: 0,
+            first: Vec::with_capacity(len),
+            last: VecDeque::with_capacity(len),
+        }
+    }
 }
 
-// #[cfg(test)]
-// mod tests {
-//     use std::io::Read;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::CapturedLog;
+
+    #[test]
+    fn test_log() {
+        let (bar, captured) =
+            CapturedLog::capture(|| vec!["foo", "bar"].into_iter().log("whee").nth(1).unwrap());
+        assert_eq!(bar, "bar");
+        assert_eq!(captured.output(), "whee: [\"foo\", \"bar\"]\n");
+    }
+
+    #[test]
+    fn test_inspect_log_captured() {
+        let (_, captured) = CapturedLog::capture(|| {
+            ["foo", "bar"]
+                .into_iter()
+                .inspect_log("whee")
+                .for_each(drop)
+        });
+        assert_eq!(
+            captured.lines().collect::<Vec<_>>(),
+            vec![r#"whee[0]: "foo""#, r#"whee[1]: "bar""#]
+        );
+    }
+
+    #[test]
+    fn test_log_with() {
+        let mut output = String::default();
+        assert_eq!(
+            vec!["foo", "bar"]
+                .into_iter()
+                .log_with("whee", |message_key: &str, items: &dyn fmt::Debug| {
+                    output = format!("{message_key}: {items:?}");
+                })
+                .nth(1)
+                .unwrap(),
+            "bar"
+        );
+        assert_eq!(output, r#"whee: ["foo", "bar"]"#);
+    }
+
+    fn capture(output: &mut Vec<String>) -> impl FnMut(&str, &dyn fmt::Debug) + '_ {
+        |message_key: &str, items: &dyn fmt::Debug| output.push(format!("{message_key}: {items:?}"))
+    }
+
+    #[test]
+    fn test_inspect_log() {
+        let mut output = Vec::default();
+        let mut iterator = (1..).inspect_log_with("whee", capture(&mut output));
+        assert_eq!(iterator.nth(1), Some(2));
+        drop(iterator);
+        assert_eq!(output, vec!["whee[0]: 1", "whee[1]: 2"]);
+    }
+
+    #[test]
+    fn test_log_take() {
+        let mut output = Vec::default();
+        assert_eq!(
+            ["foo", "bar", "baz"]
+                .into_iter()
+                .log_take_with("whee", 2, capture(&mut output))
+                .collect::<Vec<_>>(),
+            vec!["foo", "bar", "baz"]
+        );
+        assert_eq!(output, vec![r#"whee[0]: "foo""#, r#"whee[1]: "bar""#]);
+    }
+
+    #[test]
+    fn test_log_summary() {
+        let mut output = Vec::default();
+        assert_eq!(
+            (1..=5)
+                .log_summary_with("whee", 2, capture(&mut output))
+                .sum::<i32>(),
+            15
+        );
+        assert_eq!(
+            output,
+            vec!["whee: Summary { count: 5, first: [1, 2], last: [4, 5] }"]
+        );
+
+        let mut output = Vec::default();
+        let mut iterator = (1..).log_summary_with("whee", 2, capture(&mut output));
+        assert_eq!(iterator.next(), Some(1));
+        drop(iterator);
+        assert_eq!(
+            output,
+            vec!["whee: Summary { count: 1, first: [1], last: [1] }"]
+        );
+    }
+
+    #[test]
+    fn test_log_summary_overlapping() {
+        let mut output = Vec::default();
+        (1..=3)
+            .log_summary_with("whee", 2, capture(&mut output))
+            .for_each(drop);
+        assert_eq!(
+            output,
+            vec!["whee: Summary { count: 3, first: [1, 2], last: [2, 3] }"]
+        );
+
+        let mut output = Vec::default();
+        (1..=3)
+            .log_summary_with("whee", 0, capture(&mut output))
+            .for_each(drop);
+        assert_eq!(
+            output,
+            vec!["whee: Summary { count: 3, first: [], last: [] }"]
+        );
+    }
+
+    #[cfg(feature = "log")]
+    #[test]
+    fn test_log_debug() {
+        use std::sync::Mutex;
+
+        static RECORDS: Mutex<Vec<(log::Level, String, String)>> = Mutex::new(Vec::new());
+
+        struct CapturingLogger;
+
+        impl log::Log for CapturingLogger {
+            fn enabled(&self, _metadata: &log::Metadata) -> bool {
+                true
+            }
 
-//     use gag::BufferRedirect;
+            fn log(&self, record: &log::Record) {
+                RECORDS.lock().unwrap().push((
+                    record.level(),
+                    record.target().to_owned(),
+                    record.args().to_string(),
+                ));
+            }
 
-//     use super::*;
+            fn flush(&self) {}
+        }
 
-//     #[test]
-//     fn test_log() {
-//         let mut captured = BufferRedirect::stdout().unwrap();
+        log::set_logger(&CapturingLogger).unwrap();
+        log::set_max_level(log::LevelFilter::Trace);
 
-//         assert_eq!(
-//             vec!["foo", "bar"].into_iter().log("whee").nth(1).unwrap(),
-//             "bar"
-//         );
+        assert_eq!(
+            vec![1, 2]
+                .into_iter()
+                .log_debug(module_path!(), "whee")
+                .sum::<i32>(),
+            3
+        );
+        vec![3].into_iter().log_trace("foo", "whoa").for_each(drop);
+        assert_eq!(
+            *RECORDS.lock().unwrap(),
+            vec![
+                (
+                    log::Level::Debug,
+                    "squalid::iterator::tests".to_owned(),
+                    "whee: [1, 2]".to_owned()
+                ),
+                (log::Level::Trace, "foo".to_owned(), "whoa: [3]".to_owned()),
+            ]
+        );
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn test_log_event() {
+        use std::sync::{Arc, Mutex};
+
+        use tracing::{
+            field::{Field, Visit},
+            span, Event, Level, Metadata, Subscriber,
+        };
+
+        #[derive(Default)]
+        struct CapturingSubscriber {
+            events: Arc<Mutex<Vec<(Level, String)>>>,
+        }
+
+        struct FieldsVisitor<'a>(&'a mut String);
+
+        impl Visit for FieldsVisitor<'_> {
+            fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+                self.0.push_str(&format!("{}={value:?};", field.name()));
+            }
+        }
+
+        impl Subscriber for CapturingSubscriber {
+            fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+                true
+            }
+
+            fn new_span(&self, _span: &span::Attributes<'_>) -> span::Id {
+                span::Id::from_u64(1)
+            }
 
-//         let mut output = String::default();
-//         captured.read_to_string(&mut output).unwrap();
-//         assert_eq!(output, r#"whee: ["foo", "bar"]"#);
-//     }
-// }
+            fn record(&self, _span: &span::Id, _values: &span::Record<'_>) {}
+
+            fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+
+            fn event(&self, event: &Event<'_>) {
+                let mut fields = String::default();
+                event.record(&mut FieldsVisitor(&mut fields));
+                self.events
+                    .lock()
+                    .unwrap()
+                    .push((*event.metadata().level(), fields));
+            }
+
+            fn enter(&self, _span: &span::Id) {}
+
+            fn exit(&self, _span: &span::Id) {}
+        }
+
+        let subscriber = CapturingSubscriber::default();
+        let events = subscriber.events.clone();
+        tracing::subscriber::with_default(subscriber, || {
+            assert_eq!(
+                vec!["foo"]
+                    .into_iter()
+                    .log_event("whee", Level::WARN)
+                    .collect::<Vec<_>>(),
+                vec!["foo"]
+            );
+        });
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![(Level::WARN, r#"message=whee;items=["foo"];"#.to_owned())]
+        );
+    }
+}
@@ -1,23 +1,82 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(all(test, feature = "macros"))]
+extern crate self as squalid;
+
+mod and;
 mod bool;
 mod collections;
+#[cfg(feature = "alloc")]
 mod cow;
 mod default;
 mod everything;
-mod hash_map;
+#[cfg(feature = "std")]
 mod iterator;
 mod macros;
+#[cfg(feature = "alloc")]
+mod map;
+#[cfg(feature = "alloc")]
+mod non_empty;
 mod option;
+#[cfg(feature = "async")]
+mod option_async;
+mod result;
+#[cfg(all(feature = "std", any(test, feature = "testing")))]
+pub mod testing;
+#[cfg(feature = "alloc")]
 mod vec;
 
-pub use collections::Contains;
-pub use cow::{CowExt, CowStrExt, IntoCow};
+#[cfg(feature = "alloc")]
+pub use and::AndPushStr;
+pub use and::{AndClear, AndExtend, AndInsert, AndRemove, AndRetain};
+pub use collections::{Contains, SortedSlice};
+#[cfg(feature = "alloc")]
+pub use cow::{CowExt, CowSliceExt, CowStrExt, IntoCow};
+#[cfg(feature = "std")]
+pub use cow::{CowOsStrExt, CowPathExt};
 pub use default::_d;
 pub use everything::EverythingExt;
-pub use hash_map::HashMapExt;
-pub use iterator::IteratorExt;
+#[cfg(feature = "log")]
+pub use iterator::LogCrateSink;
+#[cfg(feature = "tracing")]
+pub use iterator::TracingSink;
+#[cfg(feature = "std")]
+pub use iterator::{InspectLog, IteratorExt, LogSink, LogSummary, LogTake, StdoutSink};
+#[cfg(feature = "alloc")]
+pub use map::{IteratorExtMap, MapExt, MapExt as HashMapExt, MapExtSet, MapExtVec, OnDuplicateKey};
+#[cfg(feature = "alloc")]
+pub use non_empty::{NonEmptyString, NonEmptyVec};
+#[cfg(feature = "alloc")]
+pub use option::OptionExtVec;
 pub use option::{
-    IsEmpty, NonEmpty, OptionExt, OptionExtDefault, OptionExtDeref, OptionExtIterator, OptionExtVec,
+    IntoNonEmpty, IsEmpty, NonEmpty, OptionExt, OptionExtDefault, OptionExtDeref, OptionExtIterator,
+};
+#[cfg(feature = "async")]
+pub use option_async::OptionExtAsync;
+pub use result::ResultExt;
+#[cfg(feature = "macros")]
+pub use squalid_macros::IsEmpty;
+#[cfg(feature = "alloc")]
+pub use vec::{
+    SliceExtClone, SliceExtCloneOrd, SortedVec, SortedVecExt, VecExt, VecExtClone, VecExtOrd,
+    VecExtPartialEq,
 };
-pub use vec::{SliceExtClone, SliceExtCloneOrd, VecExt, VecExtOrd};
 
 pub use crate::bool::BoolExt;
+
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "std")]
+    pub use std::sync::OnceLock;
+
+    #[cfg(feature = "fancy-regex")]
+    pub use fancy_regex;
+    #[cfg(feature = "regex")]
+    pub use regex;
+    #[cfg(feature = "serde_json")]
+    pub use serde_json;
+    #[cfg(feature = "macros")]
+    pub use squalid_macros;
+}
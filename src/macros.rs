@@ -17,22 +17,84 @@ macro_rules! return_default_if_false {
     };
 }
 
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __regex_literal {
+    ($re:literal) => {
+        $crate::__private::squalid_macros::regex_literal!($re)
+    };
+}
+
+#[cfg(not(feature = "macros"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __regex_literal {
+    ($re:literal) => {
+        $re
+    };
+}
+
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __fancy_regex_literal {
+    ($re:literal) => {
+        $crate::__private::squalid_macros::fancy_regex_literal!($re)
+    };
+}
+
+#[cfg(not(feature = "macros"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __fancy_regex_literal {
+    ($re:literal) => {
+        $re
+    };
+}
+
+#[cfg(feature = "regex")]
 #[macro_export]
 macro_rules! regex {
     ($re:literal $(,)?) => {{
-        static RE: std::sync::OnceLock<regex::Regex> = std::sync::OnceLock::new();
-        RE.get_or_init(|| regex::Regex::new($re).unwrap())
+        static RE: $crate::__private::OnceLock<$crate::__private::regex::Regex> =
+            $crate::__private::OnceLock::new();
+        RE.get_or_init(|| {
+            $crate::__private::regex::Regex::new($crate::__regex_literal!($re)).unwrap()
+        })
     }};
 }
 
+#[cfg(not(feature = "regex"))]
+#[macro_export]
+macro_rules! regex {
+    ($($tt:tt)*) => {
+        compile_error!("squalid::regex! requires enabling the `regex` feature of squalid")
+    };
+}
+
+#[cfg(feature = "fancy-regex")]
 #[macro_export]
 macro_rules! fancy_regex {
     ($re:literal $(,)?) => {{
-        static RE: std::sync::OnceLock<fancy_regex::Regex> = std::sync::OnceLock::new();
-        RE.get_or_init(|| fancy_regex::Regex::new($re).unwrap())
+        static RE: $crate::__private::OnceLock<$crate::__private::fancy_regex::Regex> =
+            $crate::__private::OnceLock::new();
+        RE.get_or_init(|| {
+            $crate::__private::fancy_regex::Regex::new($crate::__fancy_regex_literal!($re)).unwrap()
+        })
     }};
 }
 
+#[cfg(not(feature = "fancy-regex"))]
+#[macro_export]
+macro_rules! fancy_regex {
+    ($($tt:tt)*) => {
+        compile_error!(
+            "squalid::fancy_regex! requires enabling the `fancy-regex` feature of squalid"
+        )
+    };
+}
+
 #[macro_export]
 macro_rules! return_if_none {
     ($expr:expr $(,)?) => {
@@ -63,10 +125,11 @@ macro_rules! break_if_none {
     };
 }
 
+#[cfg(feature = "std")]
 #[macro_export]
 macro_rules! run_once {
     ($($stmt:stmt)*) => {{
-        static ONCE_LOCK: std::sync::OnceLock<()> = std::sync::OnceLock::new();
+        static ONCE_LOCK: $crate::__private::OnceLock<()> = $crate::__private::OnceLock::new();
         #[allow(clippy::unused_unit, redundant_semicolons)]
         ONCE_LOCK.get_or_init(|| {
             {
@@ -77,12 +140,63 @@ macro_rules! run_once {
     }}
 }
 
+#[cfg(not(feature = "std"))]
+#[macro_export]
+macro_rules! run_once {
+    ($($tt:tt)*) => {
+        compile_error!("squalid::run_once! requires enabling the `std` feature of squalid")
+    };
+}
+
+#[cfg(feature = "serde_json")]
 #[macro_export]
 macro_rules! json_object {
     ($($json:tt)+) => {
-        match serde_json::json!($($json)*) {
-            serde_json::Value::Object(value) => value,
+        match $crate::__private::serde_json::json!($($json)*) {
+            $crate::__private::serde_json::Value::Object(value) => value,
             _ => panic!("Expected object"),
         }
     };
 }
+
+#[cfg(not(feature = "serde_json"))]
+#[macro_export]
+macro_rules! json_object {
+    ($($tt:tt)*) => {
+        compile_error!(
+            "squalid::json_object! requires enabling the `serde_json` feature of squalid"
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_regex() {
+        assert!(regex!(r"^f.o$").is_match("foo"));
+        assert!(!regex!(r"^f.o$").is_match("fooo"));
+        assert!(regex!(r"\<foo\>").is_match("a foo b"));
+    }
+
+    #[cfg(feature = "fancy-regex")]
+    #[test]
+    fn test_fancy_regex() {
+        assert!(fancy_regex!(r"^(\w)\1$").is_match("oo").unwrap());
+        assert!(!fancy_regex!(r"^(\w)\1$").is_match("of").unwrap());
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_json_object() {
+        let object = json_object!({ "foo": 1 });
+        assert_eq!(object["foo"], 1);
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    #[should_panic(expected = "Expected object")]
+    fn test_json_object_panics() {
+        json_object!([1]);
+    }
+}
@@ -0,0 +1,741 @@
+use alloc::{
+    collections::{btree_map, BTreeMap, BTreeSet},
+    vec::Vec,
+};
+use core::{iter, ops::AddAssign};
+#[cfg(feature = "std")]
+use std::{
+    collections::{hash_map, HashMap, HashSet},
+    hash,
+};
+
+#[cfg(feature = "indexmap")]
+use indexmap::{map as index_map, IndexMap, IndexSet};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum OnDuplicateKey {
+    KeepFirst,
+    KeepLast,
+    Panic,
+}
+
+fn insert_with_policy<TMap>(
+    map: &mut TMap,
+    key: TMap::Key,
+    value: TMap::Value,
+    on_duplicate_key: OnDuplicateKey,
+) where
+    TMap: MapExt + Extend<(TMap::Key, TMap::Value)>,
+{
+    match on_duplicate_key {
+        OnDuplicateKey::KeepFirst => {
+            map.upsert(key, || value, |_| ());
+        }
+        OnDuplicateKey::KeepLast => map.extend(iter::once((key, value))),
+        OnDuplicateKey::Panic => {
+            map.populate(key, value);
+        }
+    }
+}
+
+pub trait MapExt: Sized {
+    type Key;
+    type Value;
+    type With<TNewKey, TNewValue>;
+
+    fn and_extend(self, iter: impl IntoIterator<Item = (Self::Key, Self::Value)>) -> Self;
+
+    fn get_or_insert_default(&mut self, key: Self::Key) -> &mut Self::Value
+    where
+        Self::Value: Default;
+
+    fn try_get_or_insert_with<TError>(
+        &mut self,
+        key: Self::Key,
+        predicate: impl FnOnce() -> Result<Self::Value, TError>,
+    ) -> Result<&mut Self::Value, TError>;
+
+    fn upsert(
+        &mut self,
+        key: Self::Key,
+        insert: impl FnOnce() -> Self::Value,
+        update: impl FnOnce(&mut Self::Value),
+    ) -> &mut Self::Value;
+
+    fn populate(&mut self, key: Self::Key, value: Self::Value) -> &mut Self::Value;
+
+    fn increment(&mut self, key: Self::Key) -> &mut Self::Value
+    where
+        Self::Value: Default + AddAssign + From<u8>;
+
+    fn map_values<TMapped>(
+        self,
+        mapper: impl FnMut(Self::Value) -> TMapped,
+    ) -> Self::With<Self::Key, TMapped>
+    where
+        Self::With<Self::Key, TMapped>: FromIterator<(Self::Key, TMapped)>;
+
+    fn try_map_values<TMapped, TError>(
+        self,
+        mapper: impl FnMut(Self::Value) -> Result<TMapped, TError>,
+    ) -> Result<Self::With<Self::Key, TMapped>, TError>
+    where
+        Self::With<Self::Key, TMapped>: FromIterator<(Self::Key, TMapped)>;
+
+    fn filter_values(self, predicate: impl FnMut(&Self::Value) -> bool) -> Self;
+    fn filter_keys(self, predicate: impl FnMut(&Self::Key) -> bool) -> Self;
+
+    fn map_keys<TMapped, TMap>(
+        self,
+        mapper: impl FnMut(Self::Key) -> TMapped,
+        on_duplicate_key: OnDuplicateKey,
+    ) -> TMap
+    where
+        TMap: MapExt<Key = TMapped, Value = Self::Value> + Default + Extend<(TMapped, Self::Value)>;
+
+    fn invert<TMap>(self, on_duplicate_key: OnDuplicateKey) -> TMap
+    where
+        TMap: MapExt<Key = Self::Value, Value = Self::Key>
+            + Default
+            + Extend<(Self::Value, Self::Key)>;
+
+    fn merge_with(
+        self,
+        other: impl IntoIterator<Item = (Self::Key, Self::Value)>,
+        combine: impl FnMut(&mut Self::Value, Self::Value),
+    ) -> Self;
+}
+
+macro_rules! impl_map_ext {
+    (
+        $type:ty,
+        $with:ty,
+        $entry:path,
+        [$($generic:tt),+]
+        $(, $($bound:tt)+)?
+    ) => {
+        impl<$($generic),+> MapExt for $type
+        $(where $($bound)+)?
+        {
+            type Key = TKey;
+            type Value = TValue;
+            type With<TNewKey, TNewValue> = $with;
+
+            fn and_extend(mut self, iter: impl IntoIterator<Item = (TKey, TValue)>) -> Self {
+                self.extend(iter);
+                self
+            }
+
+            fn get_or_insert_default(&mut self, key: TKey) -> &mut TValue
+            where
+                TValue: Default,
+            {
+                self.entry(key).or_default()
+            }
+
+            fn try_get_or_insert_with<TError>(
+                &mut self,
+                key: TKey,
+                predicate: impl FnOnce() -> Result<TValue, TError>,
+            ) -> Result<&mut TValue, TError> {
+                use $entry as Entry;
+
+                Ok(match self.entry(key) {
+                    Entry::Occupied(entry) => entry.into_mut(),
+                    Entry::Vacant(entry) => entry.insert(predicate()?),
+                })
+            }
+
+            fn upsert(
+                &mut self,
+                key: TKey,
+                insert: impl FnOnce() -> TValue,
+                update: impl FnOnce(&mut TValue),
+            ) -> &mut TValue {
+                use $entry as Entry;
+
+                match self.entry(key) {
+                    Entry::Occupied(entry) => {
+                        let value = entry.into_mut();
+                        update(value);
+                        value
+                    }
+                    Entry::Vacant(entry) => entry.insert(insert()),
+                }
+            }
+
+            fn populate(&mut self, key: TKey, value: TValue) -> &mut TValue {
+                use $entry as Entry;
+
+                match self.entry(key) {
+                    Entry::Occupied(_) => panic!("expected vacant entry"),
+                    Entry::Vacant(entry) => entry.insert(value),
+                }
+            }
+
+            fn increment(&mut self, key: TKey) -> &mut TValue
+            where
+                TValue: Default + AddAssign + From<u8>,
+            {
+                let count = self.entry(key).or_default();
+                *count += TValue::from(1);
+                count
+            }
+
+            fn map_values<TMapped>(
+                self,
+                mut mapper: impl FnMut(TValue) -> TMapped,
+            ) -> Self::With<TKey, TMapped>
+            where
+                Self::With<TKey, TMapped>: FromIterator<(TKey, TMapped)>,
+            {
+                self.into_iter()
+                    .map(|(key, value)| (key, mapper(value)))
+                    .collect()
+            }
+
+            fn try_map_values<TMapped, TError>(
+                self,
+                mut mapper: impl FnMut(TValue) -> Result<TMapped, TError>,
+            ) -> Result<Self::With<TKey, TMapped>, TError>
+            where
+                Self::With<TKey, TMapped>: FromIterator<(TKey, TMapped)>,
+            {
+                self.into_iter()
+                    .map(|(key, value)| Ok((key, mapper(value)?)))
+                    .collect()
+            }
+
+            fn filter_values(mut self, mut predicate: impl FnMut(&TValue) -> bool) -> Self {
+                self.retain(|_, value| predicate(value));
+                self
+            }
+
+            fn filter_keys(mut self, mut predicate: impl FnMut(&TKey) -> bool) -> Self {
+                self.retain(|key, _| predicate(key));
+                self
+            }
+
+            fn map_keys<TMapped, TMap>(
+                self,
+                mut mapper: impl FnMut(TKey) -> TMapped,
+                on_duplicate_key: OnDuplicateKey,
+            ) -> TMap
+            where
+                TMap: MapExt<Key = TMapped, Value = TValue> + Default + Extend<(TMapped, TValue)>,
+            {
+                let mut mapped = TMap::default();
+                for (key, value) in self {
+                    insert_with_policy(&mut mapped, mapper(key), value, on_duplicate_key);
+                }
+                mapped
+            }
+
+            fn invert<TMap>(self, on_duplicate_key: OnDuplicateKey) -> TMap
+            where
+                TMap: MapExt<Key = TValue, Value = TKey> + Default + Extend<(TValue, TKey)>,
+            {
+                let mut inverted = TMap::default();
+                for (key, value) in self {
+                    insert_with_policy(&mut inverted, value, key, on_duplicate_key);
+                }
+                inverted
+            }
+
+            fn merge_with(
+                mut self,
+                other: impl IntoIterator<Item = (TKey, TValue)>,
+                mut combine: impl FnMut(&mut TValue, TValue),
+            ) -> Self {
+                use $entry as Entry;
+
+                for (key, value) in other {
+                    match self.entry(key) {
+                        Entry::Occupied(entry) => combine(entry.into_mut(), value),
+                        Entry::Vacant(entry) => {
+                            entry.insert(value);
+                        }
+                    }
+                }
+                self
+            }
+        }
+    };
+}
+
+#[cfg(feature = "std")]
+impl_map_ext!(
+    HashMap<TKey, TValue, TBuildHasher>,
+    HashMap<TNewKey, TNewValue, TBuildHasher>,
+    hash_map::Entry,
+    [TKey, TValue, TBuildHasher],
+    TKey: Eq + hash::Hash,
+    TBuildHasher: hash::BuildHasher,
+);
+impl_map_ext!(
+    BTreeMap<TKey, TValue>,
+    BTreeMap<TNewKey, TNewValue>,
+    btree_map::Entry,
+    [TKey, TValue],
+    TKey: Ord,
+);
+#[cfg(feature = "indexmap")]
+impl_map_ext!(
+    IndexMap<TKey, TValue, TBuildHasher>,
+    IndexMap<TNewKey, TNewValue, TBuildHasher>,
+    index_map::Entry,
+    [TKey, TValue, TBuildHasher],
+    TKey: Eq + hash::Hash,
+    TBuildHasher: hash::BuildHasher,
+);
+
+pub trait MapExtVec {
+    type Key;
+    type Item;
+
+    fn push_to(&mut self, key: Self::Key, item: Self::Item);
+}
+
+macro_rules! impl_map_ext_vec {
+    ($type:ty, [$($generic:tt),+] $(, $($bound:tt)+)?) => {
+        impl<$($generic),+> MapExtVec for $type
+        $(where $($bound)+)?
+        {
+            type Key = TKey;
+            type Item = TItem;
+
+            fn push_to(&mut self, key: TKey, item: TItem) {
+                self.entry(key).or_default().push(item);
+            }
+        }
+    };
+}
+
+#[cfg(feature = "std")]
+impl_map_ext_vec!(
+    HashMap<TKey, Vec<TItem>, TBuildHasher>,
+    [TKey, TItem, TBuildHasher],
+    TKey: Eq + hash::Hash,
+    TBuildHasher: hash::BuildHasher,
+);
+impl_map_ext_vec!(BTreeMap<TKey, Vec<TItem>>, [TKey, TItem], TKey: Ord);
+#[cfg(feature = "indexmap")]
+impl_map_ext_vec!(
+    IndexMap<TKey, Vec<TItem>, TBuildHasher>,
+    [TKey, TItem, TBuildHasher],
+    TKey: Eq + hash::Hash,
+    TBuildHasher: hash::BuildHasher,
+);
+
+pub trait IteratorExtMap: Iterator + Sized {
+    fn group_into_map_by<TKey, TMap>(self, f: impl FnMut(&Self::Item) -> TKey) -> TMap
+    where
+        TMap: MapExtVec<Key = TKey, Item = Self::Item> + Default;
+}
+
+impl<TIterator: Iterator> IteratorExtMap for TIterator {
+    fn group_into_map_by<TKey, TMap>(self, mut f: impl FnMut(&Self::Item) -> TKey) -> TMap
+    where
+        TMap: MapExtVec<Key = TKey, Item = Self::Item> + Default,
+    {
+        let mut grouped = TMap::default();
+        for item in self {
+            grouped.push_to(f(&item), item);
+        }
+        grouped
+    }
+}
+
+pub trait MapExtSet {
+    type Key;
+    type Item;
+
+    fn insert_into(&mut self, key: Self::Key, item: Self::Item) -> bool;
+}
+
+macro_rules! impl_map_ext_set {
+    ($type:ty, [$($generic:tt),+] $(, $($bound:tt)+)?) => {
+        impl<$($generic),+> MapExtSet for $type
+        $(where $($bound)+)?
+        {
+            type Key = TKey;
+            type Item = TItem;
+
+            fn insert_into(&mut self, key: TKey, item: TItem) -> bool {
+                self.entry(key).or_default().insert(item)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "std")]
+impl_map_ext_set!(
+    HashMap<TKey, HashSet<TItem, TSetBuildHasher>, TBuildHasher>,
+    [TKey, TItem, TBuildHasher, TSetBuildHasher],
+    TKey: Eq + hash::Hash,
+    TItem: Eq + hash::Hash,
+    TBuildHasher: hash::BuildHasher,
+    TSetBuildHasher: hash::BuildHasher + Default,
+);
+#[cfg(feature = "std")]
+impl_map_ext_set!(
+    HashMap<TKey, BTreeSet<TItem>, TBuildHasher>,
+    [TKey, TItem, TBuildHasher],
+    TKey: Eq + hash::Hash,
+    TItem: Ord,
+    TBuildHasher: hash::BuildHasher,
+);
+#[cfg(feature = "std")]
+impl_map_ext_set!(
+    BTreeMap<TKey, HashSet<TItem, TSetBuildHasher>>,
+    [TKey, TItem, TSetBuildHasher],
+    TKey: Ord,
+    TItem: Eq + hash::Hash,
+    TSetBuildHasher: hash::BuildHasher + Default,
+);
+impl_map_ext_set!(
+    BTreeMap<TKey, BTreeSet<TItem>>,
+    [TKey, TItem],
+    TKey: Ord,
+    TItem: Ord,
+);
+#[cfg(feature = "indexmap")]
+impl_map_ext_set!(
+    IndexMap<TKey, IndexSet<TItem, TSetBuildHasher>, TBuildHasher>,
+    [TKey, TItem, TBuildHasher, TSetBuildHasher],
+    TKey: Eq + hash::Hash,
+    TItem: Eq + hash::Hash,
+    TBuildHasher: hash::BuildHasher,
+    TSetBuildHasher: hash::BuildHasher + Default,
+);
+#[cfg(feature = "indexmap")]
+impl_map_ext_set!(
+    BTreeMap<TKey, IndexSet<TItem, TSetBuildHasher>>,
+    [TKey, TItem, TSetBuildHasher],
+    TKey: Ord,
+    TItem: Eq + hash::Hash,
+    TSetBuildHasher: hash::BuildHasher + Default,
+);
+#[cfg(feature = "indexmap")]
+impl_map_ext_set!(
+    HashMap<TKey, IndexSet<TItem, TSetBuildHasher>, TBuildHasher>,
+    [TKey, TItem, TBuildHasher, TSetBuildHasher],
+    TKey: Eq + hash::Hash,
+    TItem: Eq + hash::Hash,
+    TBuildHasher: hash::BuildHasher,
+    TSetBuildHasher: hash::BuildHasher + Default,
+);
+#[cfg(feature = "indexmap")]
+impl_map_ext_set!(
+    IndexMap<TKey, HashSet<TItem, TSetBuildHasher>, TBuildHasher>,
+    [TKey, TItem, TBuildHasher, TSetBuildHasher],
+    TKey: Eq + hash::Hash,
+    TItem: Eq + hash::Hash,
+    TBuildHasher: hash::BuildHasher,
+    TSetBuildHasher: hash::BuildHasher + Default,
+);
+#[cfg(feature = "indexmap")]
+impl_map_ext_set!(
+    IndexMap<TKey, BTreeSet<TItem>, TBuildHasher>,
+    [TKey, TItem, TBuildHasher],
+    TKey: Eq + hash::Hash,
+    TItem: Ord,
+    TBuildHasher: hash::BuildHasher,
+);
+
+#[cfg(test)]
+mod tests {
+    use core::fmt;
+
+    use super::*;
+    use crate::AndInsert;
+
+    macro_rules! for_each_map {
+        ($test:ident) => {
+            $test::<BTreeMap<_, _>>();
+            #[cfg(feature = "std")]
+            $test::<HashMap<_, _>>();
+            #[cfg(feature = "indexmap")]
+            $test::<IndexMap<_, _>>();
+        };
+    }
+
+    #[test]
+    fn test_and_extend() {
+        assert_eq!(
+            BTreeMap::from([("foo", 1)]).and_extend([("bar", 2)]),
+            BTreeMap::from([("foo", 1), ("bar", 2)])
+        );
+    }
+
+    #[test]
+    fn test_get_or_insert_default() {
+        fn check<TMap>()
+        where
+            TMap: MapExt<Key = &'static str, Value = Vec<i32>>
+                + Default
+                + FromIterator<(&'static str, Vec<i32>)>
+                + PartialEq
+                + fmt::Debug,
+        {
+            let mut map = TMap::default();
+            map.get_or_insert_default("foo").push(1);
+            map.get_or_insert_default("foo").push(2);
+            assert_eq!(map, TMap::from_iter([("foo", vec![1, 2])]));
+        }
+
+        for_each_map!(check);
+    }
+
+    #[test]
+    fn test_try_get_or_insert_with() {
+        fn check<TMap>()
+        where
+            TMap: MapExt<Key = &'static str, Value = i32>
+                + Default
+                + FromIterator<(&'static str, i32)>
+                + PartialEq
+                + fmt::Debug,
+        {
+            let mut map = TMap::default();
+            assert_eq!(
+                map.try_get_or_insert_with("foo", || Ok::<_, ()>(1)),
+                Ok(&mut 1)
+            );
+            assert_eq!(
+                map.try_get_or_insert_with("foo", || Err("oops")),
+                Ok(&mut 1)
+            );
+            assert_eq!(
+                map.try_get_or_insert_with("bar", || Err("oops")),
+                Err("oops")
+            );
+            assert_eq!(map, TMap::from_iter([("foo", 1)]));
+        }
+
+        for_each_map!(check);
+    }
+
+    #[test]
+    fn test_upsert() {
+        fn check<TMap>()
+        where
+            TMap: MapExt<Key = &'static str, Value = i32> + Default,
+        {
+            let mut map = TMap::default();
+            assert_eq!(*map.upsert("foo", || 1, |value| *value *= 10), 1);
+            assert_eq!(*map.upsert("foo", || 1, |value| *value *= 10), 10);
+        }
+
+        for_each_map!(check);
+    }
+
+    #[test]
+    fn test_populate() {
+        fn check<TMap>()
+        where
+            TMap: MapExt<Key = &'static str, Value = i32>
+                + Default
+                + FromIterator<(&'static str, i32)>
+                + PartialEq
+                + fmt::Debug,
+        {
+            let mut map = TMap::default();
+            *map.populate("foo", 1) += 1;
+            assert_eq!(map, TMap::from_iter([("foo", 2)]));
+        }
+
+        for_each_map!(check);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected vacant entry")]
+    fn test_populate_existing_panics() {
+        BTreeMap::from([("foo", 1)]).populate("foo", 2);
+    }
+
+    #[test]
+    fn test_increment() {
+        fn check<TMap>()
+        where
+            TMap: MapExt<Key = char, Value = usize> + Default,
+        {
+            let mut counts = TMap::default();
+            for c in "hello".chars() {
+                counts.increment(c);
+            }
+            assert_eq!(*counts.increment('l'), 3);
+            assert_eq!(*counts.increment('h'), 2);
+        }
+
+        for_each_map!(check);
+    }
+
+    #[test]
+    fn test_push_to() {
+        fn check<TMap>()
+        where
+            TMap: MapExtVec<Key = &'static str, Item = i32>
+                + Default
+                + FromIterator<(&'static str, Vec<i32>)>
+                + PartialEq
+                + fmt::Debug,
+        {
+            let mut map = TMap::default();
+            map.push_to("foo", 1);
+            map.push_to("foo", 2);
+            map.push_to("bar", 3);
+            assert_eq!(
+                map,
+                TMap::from_iter([("foo", vec![1, 2]), ("bar", vec![3])])
+            );
+        }
+
+        for_each_map!(check);
+    }
+
+    #[test]
+    fn test_insert_into() {
+        fn check<TMap, TSet>()
+        where
+            TMap: MapExtSet<Key = &'static str, Item = i32>
+                + Default
+                + FromIterator<(&'static str, TSet)>
+                + PartialEq
+                + fmt::Debug,
+            TSet: FromIterator<i32>,
+        {
+            let mut map = TMap::default();
+            assert!(map.insert_into("foo", 1));
+            assert!(!map.insert_into("foo", 1));
+            assert!(map.insert_into("foo", 2));
+            assert_eq!(map, TMap::from_iter([("foo", TSet::from_iter([1, 2]))]));
+        }
+
+        check::<BTreeMap<_, _>, BTreeSet<_>>();
+        #[cfg(feature = "std")]
+        check::<HashMap<_, _>, HashSet<_>>();
+        #[cfg(feature = "indexmap")]
+        check::<IndexMap<_, _>, IndexSet<_>>();
+    }
+
+    #[test]
+    fn test_map_values() {
+        let btree_map = BTreeMap::from([("foo", 1), ("bar", 2)]);
+        assert_eq!(
+            btree_map.map_values(|value| value.to_string()),
+            BTreeMap::from([("foo", "1".to_owned()), ("bar", "2".to_owned())])
+        );
+    }
+
+    #[test]
+    fn test_try_map_values() {
+        let btree_map = BTreeMap::from([("foo", "1"), ("bar", "2")]);
+        assert_eq!(
+            btree_map
+                .clone()
+                .try_map_values(|value| value.parse::<i32>()),
+            Ok(BTreeMap::from([("foo", 1), ("bar", 2)]))
+        );
+        assert!(btree_map
+            .and_insert(("baz", "x"))
+            .try_map_values(|value| value.parse::<i32>())
+            .is_err());
+    }
+
+    #[test]
+    fn test_filter_values_and_keys() {
+        let btree_map = BTreeMap::from([("foo", 1), ("bar", 2), ("baz", 3)]);
+        assert_eq!(
+            btree_map.clone().filter_values(|value| *value > 1),
+            BTreeMap::from([("bar", 2), ("baz", 3)])
+        );
+        assert_eq!(
+            btree_map.filter_keys(|key| key.starts_with('b')),
+            BTreeMap::from([("bar", 2), ("baz", 3)])
+        );
+    }
+
+    #[test]
+    fn test_map_keys() {
+        let btree_map = BTreeMap::from([("a", 1), ("bb", 2), ("cc", 3)]);
+        let by_len: BTreeMap<usize, i32> = btree_map
+            .clone()
+            .map_keys(str::len, OnDuplicateKey::KeepFirst);
+        assert_eq!(by_len, BTreeMap::from([(1, 1), (2, 2)]));
+        let by_len: BTreeMap<usize, i32> = btree_map.map_keys(str::len, OnDuplicateKey::KeepLast);
+        assert_eq!(by_len, BTreeMap::from([(1, 1), (2, 3)]));
+    }
+
+    #[test]
+    #[should_panic(expected = "expected vacant entry")]
+    fn test_map_keys_duplicate_panics() {
+        let _: BTreeMap<usize, i32> =
+            BTreeMap::from([("bb", 2), ("cc", 3)]).map_keys(str::len, OnDuplicateKey::Panic);
+    }
+
+    #[test]
+    fn test_invert() {
+        let inverted: BTreeMap<i32, &str> =
+            BTreeMap::from([("foo", 1), ("bar", 2)]).invert(OnDuplicateKey::Panic);
+        assert_eq!(inverted, BTreeMap::from([(1, "foo"), (2, "bar")]));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_invert_into_other_map_type() {
+        let inverted: HashMap<i32, &str> =
+            BTreeMap::from([("foo", 1), ("bar", 1)]).invert(OnDuplicateKey::KeepFirst);
+        assert_eq!(inverted, HashMap::from([(1, "bar")]));
+    }
+
+    #[test]
+    fn test_merge_with() {
+        assert_eq!(
+            BTreeMap::from([("foo", 1), ("bar", 2)])
+                .merge_with([("foo", 10), ("baz", 3)], |value, other| *value += other),
+            BTreeMap::from([("foo", 11), ("bar", 2), ("baz", 3)])
+        );
+    }
+
+    #[test]
+    fn test_group_into_map_by() {
+        let grouped: BTreeMap<usize, Vec<&str>> = ["foo", "ba", "bar", "b"]
+            .into_iter()
+            .group_into_map_by(|value| value.len());
+        assert_eq!(
+            grouped,
+            BTreeMap::from([(1, vec!["b"]), (2, vec!["ba"]), (3, vec!["foo", "bar"])])
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_hash_map_with() {
+        assert_eq!(
+            HashMap::from([("foo", 1), ("bar", 2)]).map_values(|value| value * 2),
+            HashMap::from([("foo", 2), ("bar", 4)])
+        );
+    }
+
+    #[cfg(feature = "indexmap")]
+    #[test]
+    fn test_index_map_preserves_order() {
+        let mut index_map: IndexMap<&str, i32> = Default::default();
+        index_map.increment("foo");
+        index_map.populate("bar", 1);
+        index_map.upsert("foo", || 0, |value| *value += 10);
+        assert_eq!(
+            index_map.into_iter().collect::<Vec<_>>(),
+            vec![("foo", 11), ("bar", 1)]
+        );
+
+        let mut index_map: IndexMap<&str, IndexSet<i32>> = Default::default();
+        index_map.insert_into("foo", 2);
+        index_map.insert_into("foo", 1);
+        assert_eq!(
+            index_map["foo"].iter().copied().collect::<Vec<_>>(),
+            vec![2, 1]
+        );
+    }
+}
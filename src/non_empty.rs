@@ -0,0 +1,355 @@
+use alloc::{string::String, vec, vec::Vec};
+use core::{
+    fmt,
+    ops::{Deref, DerefMut},
+    slice,
+};
+
+use crate::IntoNonEmpty;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NonEmptyVec<T>(Vec<T>);
+
+impl<T> NonEmptyVec<T> {
+    pub fn new(first: T) -> Self {
+        Self(vec![first])
+    }
+
+    pub fn from_vec(vec: Vec<T>) -> Option<Self> {
+        (!vec.is_empty()).then_some(Self(vec))
+    }
+
+    pub fn try_from_iter(iter: impl IntoIterator<Item = T>) -> Option<Self> {
+        Self::from_vec(iter.into_iter().collect())
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.0
+    }
+
+    pub fn as_vec(&self) -> &Vec<T> {
+        &self.0
+    }
+
+    pub fn first(&self) -> &T {
+        &self.0[0]
+    }
+
+    pub fn first_mut(&mut self) -> &mut T {
+        &mut self.0[0]
+    }
+
+    pub fn last(&self) -> &T {
+        self.0.last().unwrap()
+    }
+
+    pub fn last_mut(&mut self) -> &mut T {
+        self.0.last_mut().unwrap()
+    }
+
+    pub fn split_first(&self) -> (&T, &[T]) {
+        self.0.split_first().unwrap()
+    }
+
+    pub fn split_last(&self) -> (&T, &[T]) {
+        self.0.split_last().unwrap()
+    }
+
+    pub fn push(&mut self, item: T) {
+        self.0.push(item);
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        (self.0.len() > 1).then(|| self.0.pop().unwrap())
+    }
+
+    pub fn reduce(self, f: impl FnMut(T, T) -> T) -> T {
+        self.0.into_iter().reduce(f).unwrap()
+    }
+
+    pub fn map<TMapped>(self, mapper: impl FnMut(T) -> TMapped) -> NonEmptyVec<TMapped> {
+        NonEmptyVec(self.0.into_iter().map(mapper).collect())
+    }
+
+    pub fn max(&self) -> &T
+    where
+        T: Ord,
+    {
+        self.0.iter().max().unwrap()
+    }
+
+    pub fn min(&self) -> &T
+    where
+        T: Ord,
+    {
+        self.0.iter().min().unwrap()
+    }
+
+    pub fn max_by_key<TKey: Ord>(&self, f: impl FnMut(&&T) -> TKey) -> &T {
+        self.0.iter().max_by_key(f).unwrap()
+    }
+
+    pub fn min_by_key<TKey: Ord>(&self, f: impl FnMut(&&T) -> TKey) -> &T {
+        self.0.iter().min_by_key(f).unwrap()
+    }
+}
+
+impl<T> Deref for NonEmptyVec<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for NonEmptyVec<T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        &mut self.0
+    }
+}
+
+impl<T> TryFrom<Vec<T>> for NonEmptyVec<T> {
+    type Error = Vec<T>;
+
+    fn try_from(vec: Vec<T>) -> Result<Self, Vec<T>> {
+        if vec.is_empty() {
+            Err(vec)
+        } else {
+            Ok(Self(vec))
+        }
+    }
+}
+
+impl<T> From<NonEmptyVec<T>> for Vec<T> {
+    fn from(non_empty: NonEmptyVec<T>) -> Self {
+        non_empty.0
+    }
+}
+
+impl<T> IntoIterator for NonEmptyVec<T> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    fn into_iter(self) -> vec::IntoIter<T> {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a NonEmptyVec<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> slice::Iter<'a, T> {
+        self.0.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut NonEmptyVec<T> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> slice::IterMut<'a, T> {
+        self.0.iter_mut()
+    }
+}
+
+impl<T> Extend<T> for NonEmptyVec<T> {
+    fn extend<TIterator: IntoIterator<Item = T>>(&mut self, iter: TIterator) {
+        self.0.extend(iter);
+    }
+}
+
+impl<T> IntoNonEmpty for Vec<T> {
+    type NonEmpty = NonEmptyVec<T>;
+
+    fn into_non_empty(self) -> Option<NonEmptyVec<T>> {
+        NonEmptyVec::from_vec(self)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonEmptyString(String);
+
+impl NonEmptyString {
+    pub fn from_string(string: String) -> Option<Self> {
+        (!string.is_empty()).then_some(Self(string))
+    }
+
+    pub fn try_from_iter<TItem>(iter: impl IntoIterator<Item = TItem>) -> Option<Self>
+    where
+        String: FromIterator<TItem>,
+    {
+        Self::from_string(iter.into_iter().collect())
+    }
+
+    pub fn into_string(self) -> String {
+        self.0
+    }
+
+    pub fn as_string(&self) -> &String {
+        &self.0
+    }
+
+    pub fn first(&self) -> char {
+        self.0.chars().next().unwrap()
+    }
+
+    pub fn last(&self) -> char {
+        self.0.chars().next_back().unwrap()
+    }
+
+    pub fn split_first(&self) -> (char, &str) {
+        let first = self.first();
+        (first, &self.0[first.len_utf8()..])
+    }
+
+    pub fn push(&mut self, char: char) {
+        self.0.push(char);
+    }
+
+    pub fn push_str(&mut self, string: &str) {
+        self.0.push_str(string);
+    }
+}
+
+impl Deref for NonEmptyString {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for NonEmptyString {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for NonEmptyString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl PartialEq<str> for NonEmptyString {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for NonEmptyString {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl TryFrom<String> for NonEmptyString {
+    type Error = String;
+
+    fn try_from(string: String) -> Result<Self, String> {
+        if string.is_empty() {
+            Err(string)
+        } else {
+            Ok(Self(string))
+        }
+    }
+}
+
+impl From<NonEmptyString> for String {
+    fn from(non_empty: NonEmptyString) -> Self {
+        non_empty.0
+    }
+}
+
+impl IntoNonEmpty for String {
+    type NonEmpty = NonEmptyString;
+
+    fn into_non_empty(self) -> Option<NonEmptyString> {
+        NonEmptyString::from_string(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NonEmpty;
+
+    #[test]
+    fn test_non_empty_typed_vec() {
+        let non_empty = vec![3, 1, 2].non_empty_typed().unwrap();
+        assert_eq!(*non_empty.first(), 3);
+        assert_eq!(*non_empty.last(), 2);
+        assert_eq!(*non_empty.max(), 3);
+        assert_eq!(*non_empty.min(), 1);
+        assert_eq!(non_empty.split_first(), (&3, &[1, 2][..]));
+        assert_eq!(non_empty.len(), 3);
+        assert_eq!(non_empty.reduce(|a, b| a + b), 6);
+
+        assert_eq!(Vec::<i32>::new().non_empty_typed(), None);
+    }
+
+    #[test]
+    fn test_non_empty_typed_option() {
+        assert_eq!(Some(vec![1]).non_empty_typed(), Some(NonEmptyVec::new(1)));
+        assert_eq!(Some(Vec::<i32>::new()).non_empty_typed(), None);
+        assert_eq!(None::<Vec<i32>>.non_empty_typed(), None);
+    }
+
+    #[test]
+    fn test_non_empty_vec_try_from_iter() {
+        assert_eq!(
+            NonEmptyVec::try_from_iter(1..3).map(NonEmptyVec::into_vec),
+            Some(vec![1, 2])
+        );
+        assert_eq!(NonEmptyVec::try_from_iter(1..1), None);
+    }
+
+    #[test]
+    fn test_non_empty_vec_into_iter() {
+        let mut non_empty = NonEmptyVec::new(1);
+        non_empty.push(2);
+        for value in &mut non_empty {
+            *value *= 10;
+        }
+        assert_eq!((&non_empty).into_iter().sum::<i32>(), 30);
+        assert_eq!(non_empty.into_iter().collect::<Vec<_>>(), vec![10, 20]);
+    }
+
+    #[test]
+    fn test_non_empty_vec_pop() {
+        let mut non_empty = NonEmptyVec::try_from(vec![1, 2]).unwrap();
+        assert_eq!(non_empty.pop(), Some(2));
+        assert_eq!(non_empty.pop(), None);
+        assert_eq!(non_empty.into_vec(), vec![1]);
+    }
+
+    #[test]
+    fn test_non_empty_vec_try_from() {
+        assert_eq!(NonEmptyVec::try_from(Vec::<i32>::new()), Err(vec![]));
+        assert_eq!(Vec::from(NonEmptyVec::try_from(vec![1]).unwrap()), vec![1]);
+    }
+
+    #[test]
+    fn test_non_empty_typed_string() {
+        let non_empty = "héllo".to_owned().non_empty_typed().unwrap();
+        assert_eq!(non_empty.first(), 'h');
+        assert_eq!(non_empty.last(), 'o');
+        assert_eq!(non_empty.split_first(), ('h', "éllo"));
+        assert_eq!(non_empty.len(), 6);
+        assert_eq!(non_empty, "héllo");
+        assert_eq!(non_empty.to_string(), "héllo");
+
+        assert_eq!(String::new().non_empty_typed(), None);
+        assert_eq!(Some(String::new()).non_empty_typed(), None);
+    }
+
+    #[test]
+    fn test_non_empty_string_try_from_iter() {
+        assert_eq!(
+            NonEmptyString::try_from_iter(['a', 'b']).map(NonEmptyString::into_string),
+            Some("ab".to_owned())
+        );
+        assert_eq!(NonEmptyString::try_from_iter(Vec::<char>::new()), None);
+    }
+}
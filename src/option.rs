@@ -1,5 +1,21 @@
-use std::{iter, ops::Deref};
-
+#[cfg(feature = "alloc")]
+use alloc::{
+    borrow::Cow,
+    boxed::Box,
+    collections::{BTreeMap, BTreeSet, BinaryHeap, VecDeque},
+    string::String,
+    vec::Vec,
+};
+use core::{iter, ops::Deref};
+#[cfg(feature = "std")]
+use std::{
+    collections::{HashMap, HashSet},
+    ffi::{OsStr, OsString},
+    path::{Path, PathBuf},
+};
+
+#[cfg(feature = "indexmap")]
+use indexmap::{IndexMap, IndexSet};
 use itertools::Either;
 
 pub trait OptionExt {
@@ -75,8 +91,10 @@ pub trait OptionExt {
     where
         Self::Unwrapped: PartialEq<TOther>;
 
+    #[cfg(feature = "alloc")]
     fn push_if(self, vec: &mut Vec<Self::Unwrapped>);
 
+    #[cfg(feature = "alloc")]
     fn expect_else(self, message: impl FnOnce() -> String) -> Self::Unwrapped;
 
     fn populate(&mut self, value: Self::Unwrapped) -> &mut Self::Unwrapped;
@@ -100,7 +118,7 @@ impl<TValue> OptionExt for Option<TValue> {
     }
 
     fn is_none_or_matches(self, predicate: impl FnOnce(Self::Unwrapped) -> bool) -> bool {
-        self.map_or(true, predicate)
+        self.is_none_or(predicate)
     }
 
     fn try_get_or_insert_with<TError>(
@@ -229,15 +247,14 @@ impl<TValue> OptionExt for Option<TValue> {
         self.filter(|value| value == &other)
     }
 
+    #[cfg(feature = "alloc")]
     fn push_if(self, vec: &mut Vec<Self::Unwrapped>) {
-        match self {
-            Some(value) => {
-                vec.push(value);
-            }
-            None => {}
+        if let Some(value) = self {
+            vec.push(value);
         }
     }
 
+    #[cfg(feature = "alloc")]
     fn expect_else(self, message: impl FnOnce() -> String) -> Self::Unwrapped {
         match self {
             Some(value) => value,
@@ -265,8 +282,8 @@ macro_rules! impl_is_empty {
             }
         }
     };
-    ($type:ty, $generic:ident $(,)?) => {
-        impl<$generic> IsEmpty for $type {
+    ($type:ty, $($generic:tt),+ $(,)?) => {
+        impl<$($generic),+> IsEmpty for $type {
             fn _is_empty(&self) -> bool {
                 self.is_empty()
             }
@@ -275,23 +292,140 @@ macro_rules! impl_is_empty {
 }
 
 impl_is_empty!(&str);
+impl_is_empty!(&[TItem], TItem);
+#[cfg(feature = "alloc")]
 impl_is_empty!(&String);
+#[cfg(feature = "alloc")]
 impl_is_empty!(String);
-impl_is_empty!(&[TItem], TItem);
+#[cfg(feature = "alloc")]
 impl_is_empty!(&Vec<TItem>, TItem);
+#[cfg(feature = "alloc")]
 impl_is_empty!(Vec<TItem>, TItem);
+#[cfg(feature = "alloc")]
+impl_is_empty!(&Box<str>);
+#[cfg(feature = "alloc")]
+impl_is_empty!(Box<str>);
+#[cfg(feature = "alloc")]
+impl_is_empty!(&Box<[TItem]>, TItem);
+#[cfg(feature = "alloc")]
+impl_is_empty!(Box<[TItem]>, TItem);
+#[cfg(feature = "alloc")]
+impl_is_empty!(&Cow<'a, str>, 'a);
+#[cfg(feature = "alloc")]
+impl_is_empty!(Cow<'a, str>, 'a);
+#[cfg(feature = "alloc")]
+impl_is_empty!(&VecDeque<TItem>, TItem);
+#[cfg(feature = "alloc")]
+impl_is_empty!(VecDeque<TItem>, TItem);
+#[cfg(feature = "alloc")]
+impl_is_empty!(&BTreeSet<TItem>, TItem);
+#[cfg(feature = "alloc")]
+impl_is_empty!(BTreeSet<TItem>, TItem);
+#[cfg(feature = "alloc")]
+impl_is_empty!(&BTreeMap<TKey, TValue>, TKey, TValue);
+#[cfg(feature = "alloc")]
+impl_is_empty!(BTreeMap<TKey, TValue>, TKey, TValue);
+#[cfg(feature = "alloc")]
+impl_is_empty!(&BinaryHeap<TItem>, TItem);
+#[cfg(feature = "alloc")]
+impl_is_empty!(BinaryHeap<TItem>, TItem);
+#[cfg(feature = "std")]
+impl_is_empty!(&HashSet<TItem, TBuildHasher>, TItem, TBuildHasher);
+#[cfg(feature = "std")]
+impl_is_empty!(HashSet<TItem, TBuildHasher>, TItem, TBuildHasher);
+#[cfg(feature = "std")]
+impl_is_empty!(&HashMap<TKey, TValue, TBuildHasher>, TKey, TValue, TBuildHasher);
+#[cfg(feature = "std")]
+impl_is_empty!(HashMap<TKey, TValue, TBuildHasher>, TKey, TValue, TBuildHasher);
+#[cfg(feature = "indexmap")]
+impl_is_empty!(&IndexSet<TItem, TBuildHasher>, TItem, TBuildHasher);
+#[cfg(feature = "indexmap")]
+impl_is_empty!(IndexSet<TItem, TBuildHasher>, TItem, TBuildHasher);
+#[cfg(feature = "indexmap")]
+impl_is_empty!(&IndexMap<TKey, TValue, TBuildHasher>, TKey, TValue, TBuildHasher);
+#[cfg(feature = "indexmap")]
+impl_is_empty!(IndexMap<TKey, TValue, TBuildHasher>, TKey, TValue, TBuildHasher);
+#[cfg(feature = "std")]
+impl_is_empty!(&OsStr);
+#[cfg(feature = "std")]
+impl_is_empty!(&OsString);
+#[cfg(feature = "std")]
+impl_is_empty!(OsString);
+#[cfg(feature = "std")]
+impl_is_empty!(&Cow<'a, OsStr>, 'a);
+#[cfg(feature = "std")]
+impl_is_empty!(Cow<'a, OsStr>, 'a);
+
+impl<TItem, const T_LEN: usize> IsEmpty for [TItem; T_LEN] {
+    fn _is_empty(&self) -> bool {
+        T_LEN == 0
+    }
+}
+
+impl<TItem, const T_LEN: usize> IsEmpty for &[TItem; T_LEN] {
+    fn _is_empty(&self) -> bool {
+        T_LEN == 0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<TItem: Clone> IsEmpty for Cow<'_, [TItem]> {
+    fn _is_empty(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<TItem: Clone> IsEmpty for &Cow<'_, [TItem]> {
+    fn _is_empty(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+#[cfg(feature = "std")]
+macro_rules! impl_is_empty_path {
+    ($type:ty $(, $lifetime:lifetime)? $(,)?) => {
+        impl$(<$lifetime>)? IsEmpty for $type {
+            fn _is_empty(&self) -> bool {
+                self.as_os_str().is_empty()
+            }
+        }
+    };
+}
+
+#[cfg(feature = "std")]
+impl_is_empty_path!(&Path);
+#[cfg(feature = "std")]
+impl_is_empty_path!(&PathBuf);
+#[cfg(feature = "std")]
+impl_is_empty_path!(PathBuf);
+#[cfg(feature = "std")]
+impl_is_empty_path!(&Cow<'a, Path>, 'a);
+#[cfg(feature = "std")]
+impl_is_empty_path!(Cow<'a, Path>, 'a);
+
+pub trait IntoNonEmpty: Sized {
+    type NonEmpty;
+
+    fn into_non_empty(self) -> Option<Self::NonEmpty>;
+}
 
 pub trait NonEmpty {
+    type Unwrapped;
     type Optional;
 
     fn non_empty(self) -> Self::Optional;
     fn is_non_empty(&self) -> bool;
+    fn non_empty_typed(self) -> Option<<Self::Unwrapped as IntoNonEmpty>::NonEmpty>
+    where
+        Self::Unwrapped: IntoNonEmpty;
 }
 
 impl<T> NonEmpty for T
 where
     T: IsEmpty,
 {
+    type Unwrapped = Self;
     type Optional = Option<Self>;
 
     fn non_empty(self) -> Self::Optional {
@@ -301,12 +435,20 @@ where
     fn is_non_empty(&self) -> bool {
         !self._is_empty()
     }
+
+    fn non_empty_typed(self) -> Option<<Self as IntoNonEmpty>::NonEmpty>
+    where
+        Self: IntoNonEmpty,
+    {
+        self.into_non_empty()
+    }
 }
 
 impl<T> NonEmpty for Option<T>
 where
     T: IsEmpty,
 {
+    type Unwrapped = T;
     type Optional = Self;
 
     fn non_empty(self) -> Self::Optional {
@@ -316,6 +458,13 @@ where
     fn is_non_empty(&self) -> bool {
         self.as_ref().filter(|value| !value._is_empty()).is_some()
     }
+
+    fn non_empty_typed(self) -> Option<<T as IntoNonEmpty>::NonEmpty>
+    where
+        T: IntoNonEmpty,
+    {
+        self.and_then(IntoNonEmpty::into_non_empty)
+    }
 }
 
 pub trait OptionExtDeref {
@@ -357,21 +506,20 @@ impl<TIterator: Iterator> OptionExtIterator for Option<TIterator> {
     }
 }
 
+#[cfg(feature = "alloc")]
 pub trait OptionExtVec {
     type Item;
 
     fn extend_if(self, vec: &mut Vec<Self::Item>);
 }
 
+#[cfg(feature = "alloc")]
 impl<TItem> OptionExtVec for Option<Vec<TItem>> {
     type Item = TItem;
 
     fn extend_if(self, vec: &mut Vec<Self::Item>) {
-        match self {
-            Some(value) => {
-                vec.extend(value.into_iter());
-            }
-            None => {}
+        if let Some(value) = self {
+            vec.extend(value);
         }
     }
 }
@@ -436,9 +584,192 @@ mod tests {
         }
 
         assert!(matches!(Some(Foo::Bar).if_is(Foo::Bar), Some(Foo::Bar)));
-        assert!(matches!(Some(Foo::Baz).if_is(Foo::Bar), None));
+        assert!(Some(Foo::Baz).if_is(Foo::Bar).is_none());
+    }
+
+    #[test]
+    fn test_non_empty_str_and_slice() {
+        assert_eq!("foo".non_empty(), Some("foo"));
+        assert_eq!("".non_empty(), None);
+        assert!([1].as_slice().is_non_empty());
+        assert!((&[] as &[i32]).non_empty().is_none());
+    }
+
+    #[test]
+    fn test_non_empty_array() {
+        assert_eq!([1, 2].non_empty(), Some([1, 2]));
+        assert_eq!([0; 0].non_empty(), None);
+        assert!((&[1]).non_empty().is_some());
+        assert!((&[0; 0]).non_empty().is_none());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_non_empty_box() {
+        let boxed_str: Box<str> = "foo".into();
+        assert!((&boxed_str).non_empty().is_some());
+        assert_eq!(boxed_str.non_empty().as_deref(), Some("foo"));
+        assert_eq!(Box::<str>::from("").non_empty(), None);
+
+        let boxed_slice: Box<[i32]> = vec![1].into_boxed_slice();
+        assert!((&boxed_slice).non_empty().is_some());
+        assert!(boxed_slice.non_empty().is_some());
+        assert!(Box::<[i32]>::default().non_empty().is_none());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_non_empty_cow() {
+        assert_eq!(Cow::Borrowed("foo").non_empty(), Some(Cow::Borrowed("foo")));
+        assert_eq!(Cow::<str>::Owned(String::default()).non_empty(), None);
+        assert!((&Cow::Borrowed("foo")).non_empty().is_some());
+
+        assert!(Cow::Borrowed(&[1][..]).non_empty().is_some());
+        assert!(!Cow::<[i32]>::Owned(vec![]).is_non_empty());
+        assert!((&Cow::Borrowed(&[] as &[i32])).non_empty().is_none());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_non_empty_alloc_collections() {
+        assert!(VecDeque::from([1]).non_empty().is_some());
+        assert!(VecDeque::<i32>::default().non_empty().is_none());
+        assert!((&VecDeque::from([1])).non_empty().is_some());
+
+        assert!(BTreeSet::from([1]).non_empty().is_some());
+        assert!(BTreeSet::<i32>::default().non_empty().is_none());
+        assert!((&BTreeSet::<i32>::default()).non_empty().is_none());
+
+        assert!(BTreeMap::from([(1, 2)]).non_empty().is_some());
+        assert!(BTreeMap::<i32, i32>::default().non_empty().is_none());
+        assert!((&BTreeMap::from([(1, 2)])).non_empty().is_some());
+
+        assert!(BinaryHeap::from([1]).is_non_empty());
+        assert!(BinaryHeap::<i32>::default().non_empty().is_none());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_non_empty_hash_collections() {
+        assert!(HashSet::from([1]).non_empty().is_some());
+        assert!(HashSet::<i32>::default().non_empty().is_none());
+        assert!((&HashSet::from([1])).non_empty().is_some());
+
+        assert!(HashMap::from([(1, 2)]).non_empty().is_some());
+        assert!(HashMap::<i32, i32>::default().non_empty().is_none());
+        assert!((&HashMap::<i32, i32>::default()).non_empty().is_none());
+    }
+
+    #[cfg(feature = "indexmap")]
+    #[test]
+    fn test_non_empty_index_collections() {
+        assert!(IndexSet::from([1]).non_empty().is_some());
+        assert!(IndexSet::<i32>::default().non_empty().is_none());
+        assert!((&IndexMap::from([(1, 2)])).non_empty().is_some());
+        assert!(IndexMap::<i32, i32>::default().non_empty().is_none());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_non_empty_os_str() {
+        assert_eq!(OsStr::new("foo").non_empty(), Some(OsStr::new("foo")));
+        assert_eq!(OsStr::new("").non_empty(), None);
+        assert!(OsString::from("foo").non_empty().is_some());
+        assert!(OsString::default().non_empty().is_none());
+        assert!((&OsString::from("foo")).non_empty().is_some());
+        assert!(Cow::Borrowed(OsStr::new("foo")).is_non_empty());
+        assert!(!Cow::<OsStr>::Owned(OsString::default()).is_non_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_non_empty_path() {
+        assert_eq!(Path::new("foo").non_empty(), Some(Path::new("foo")));
+        assert_eq!(Path::new("").non_empty(), None);
+        assert!(PathBuf::from("foo").non_empty().is_some());
+        assert!(PathBuf::default().non_empty().is_none());
+        assert!((&PathBuf::from("foo")).non_empty().is_some());
+        assert!(Cow::Borrowed(Path::new("foo")).is_non_empty());
+        assert!((&Cow::<Path>::Owned(PathBuf::default()))
+            .non_empty()
+            .is_none());
+    }
+
+    #[cfg(all(feature = "macros", feature = "alloc"))]
+    #[test]
+    fn test_derive_is_empty_struct() {
+        fn is_zero(value: &u32) -> bool {
+            *value == 0
+        }
+
+        #[derive(Default, crate::IsEmpty)]
+        struct Request {
+            name: String,
+            tags: Vec<String>,
+            #[is_empty(skip)]
+            #[allow(dead_code)]
+            version: u32,
+            #[is_empty(with = is_zero)]
+            count: u32,
+        }
+
+        assert!(Request::default().non_empty().is_none());
+        assert!(Request {
+            version: 1,
+            ..Default::default()
+        }
+        .non_empty()
+        .is_none());
+        assert!(Request {
+            name: "foo".to_owned(),
+            ..Default::default()
+        }
+        .is_non_empty());
+        assert!(Request {
+            tags: vec!["foo".to_owned()],
+            ..Default::default()
+        }
+        .is_non_empty());
+        assert!(Request {
+            count: 1,
+            ..Default::default()
+        }
+        .is_non_empty());
+    }
+
+    #[cfg(all(feature = "macros", feature = "alloc"))]
+    #[test]
+    fn test_derive_is_empty_generic_tuple_struct() {
+        #[derive(crate::IsEmpty)]
+        struct Wrapper<T>(Vec<T>, &'static str);
+
+        assert!(Wrapper::<i32>(vec![], "").non_empty().is_none());
+        assert!(Wrapper(vec![1], "").is_non_empty());
+        assert!(Wrapper::<i32>(vec![], "foo").is_non_empty());
+    }
+
+    #[cfg(feature = "macros")]
+    #[test]
+    fn test_derive_is_empty_enum() {
+        #[derive(crate::IsEmpty)]
+        #[allow(dead_code)]
+        enum Filter {
+            #[is_empty]
+            All,
+            Named(&'static str),
+            #[is_empty]
+            Range {
+                from: u32,
+                to: u32,
+            },
+        }
+
+        assert!(Filter::All.non_empty().is_none());
+        assert!(Filter::Range { from: 1, to: 2 }.non_empty().is_none());
+        assert!(Filter::Named("foo").is_non_empty());
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn test_push_if() {
         let mut vec: Vec<String> = Default::default();
@@ -448,6 +779,7 @@ mod tests {
         assert_eq!(vec, vec!["foo".to_owned()]);
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn test_extend_if() {
         let mut vec: Vec<String> = Default::default();
@@ -459,12 +791,14 @@ mod tests {
         assert_eq!(vec, vec!["foo".to_owned()]);
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
     #[should_panic]
     fn test_expect_else_panics() {
         Option::<String>::None.expect_else(|| "Hi".to_owned());
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn test_expect_else() {
         assert_eq!(
@@ -0,0 +1,226 @@
+use core::future::Future;
+
+pub trait OptionExtAsync {
+    type Unwrapped;
+
+    fn map_async<TMapped, TFuture: Future<Output = TMapped>>(
+        self,
+        mapper: impl FnOnce(Self::Unwrapped) -> TFuture,
+    ) -> impl Future<Output = Option<TMapped>>;
+
+    fn try_map_async<TMapped, TError, TFuture: Future<Output = Result<TMapped, TError>>>(
+        self,
+        mapper: impl FnOnce(Self::Unwrapped) -> TFuture,
+    ) -> impl Future<Output = Result<Option<TMapped>, TError>>;
+
+    fn and_then_async<TMapped, TFuture: Future<Output = Option<TMapped>>>(
+        self,
+        mapper: impl FnOnce(Self::Unwrapped) -> TFuture,
+    ) -> impl Future<Output = Option<TMapped>>;
+
+    fn try_filter_async<TError, TFuture: Future<Output = Result<bool, TError>>>(
+        self,
+        predicate: impl FnOnce(&Self::Unwrapped) -> TFuture,
+    ) -> impl Future<Output = Result<Option<Self::Unwrapped>, TError>>;
+
+    fn unwrap_or_else_async<TFuture: Future<Output = Self::Unwrapped>>(
+        self,
+        default: impl FnOnce() -> TFuture,
+    ) -> impl Future<Output = Self::Unwrapped>;
+
+    fn try_get_or_insert_with_async<
+        TError,
+        TFuture: Future<Output = Result<Self::Unwrapped, TError>>,
+    >(
+        &mut self,
+        predicate: impl FnOnce() -> TFuture,
+    ) -> impl Future<Output = Result<&mut Self::Unwrapped, TError>>;
+}
+
+impl<TValue> OptionExtAsync for Option<TValue> {
+    type Unwrapped = TValue;
+
+    async fn map_async<TMapped, TFuture: Future<Output = TMapped>>(
+        self,
+        mapper: impl FnOnce(TValue) -> TFuture,
+    ) -> Option<TMapped> {
+        match self {
+            Some(value) => Some(mapper(value).await),
+            None => None,
+        }
+    }
+
+    async fn try_map_async<TMapped, TError, TFuture: Future<Output = Result<TMapped, TError>>>(
+        self,
+        mapper: impl FnOnce(TValue) -> TFuture,
+    ) -> Result<Option<TMapped>, TError> {
+        Ok(match self {
+            Some(value) => Some(mapper(value).await?),
+            None => None,
+        })
+    }
+
+    async fn and_then_async<TMapped, TFuture: Future<Output = Option<TMapped>>>(
+        self,
+        mapper: impl FnOnce(TValue) -> TFuture,
+    ) -> Option<TMapped> {
+        match self {
+            Some(value) => mapper(value).await,
+            None => None,
+        }
+    }
+
+    async fn try_filter_async<TError, TFuture: Future<Output = Result<bool, TError>>>(
+        self,
+        predicate: impl FnOnce(&TValue) -> TFuture,
+    ) -> Result<Option<TValue>, TError> {
+        Ok(match self {
+            None => None,
+            Some(value) => {
+                if predicate(&value).await? {
+                    Some(value)
+                } else {
+                    None
+                }
+            }
+        })
+    }
+
+    async fn unwrap_or_else_async<TFuture: Future<Output = TValue>>(
+        self,
+        default: impl FnOnce() -> TFuture,
+    ) -> TValue {
+        match self {
+            Some(value) => value,
+            None => default().await,
+        }
+    }
+
+    async fn try_get_or_insert_with_async<
+        TError,
+        TFuture: Future<Output = Result<TValue, TError>>,
+    >(
+        &mut self,
+        predicate: impl FnOnce() -> TFuture,
+    ) -> Result<&mut TValue, TError> {
+        match self {
+            Some(value) => Ok(value),
+            None => Ok(self.insert(predicate().await?)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        pin::pin,
+        sync::Arc,
+        task::{Context, Poll, Wake, Waker},
+        thread::{self, Thread},
+    };
+
+    use super::*;
+
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    fn block_on<TFuture: Future>(future: TFuture) -> TFuture::Output {
+        let mut future = pin!(future);
+        let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+        let mut context = Context::from_waker(&waker);
+        loop {
+            match future.as_mut().poll(&mut context) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+
+    #[test]
+    fn test_map_async() {
+        assert_eq!(
+            block_on(Some(1).map_async(|value| async move { value + 1 })),
+            Some(2)
+        );
+        assert_eq!(
+            block_on(None::<i32>.map_async(|value| async move { value + 1 })),
+            None
+        );
+    }
+
+    #[test]
+    fn test_try_map_async() {
+        assert_eq!(
+            block_on(Some(1).try_map_async(|value| async move { Ok::<_, ()>(value + 1) })),
+            Ok(Some(2))
+        );
+        assert_eq!(
+            block_on(Some(1).try_map_async(|_| async { Err::<i32, _>("oops") })),
+            Err("oops")
+        );
+        assert_eq!(
+            block_on(None::<i32>.try_map_async(|_| async { Err::<i32, _>("oops") })),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn test_and_then_async() {
+        assert_eq!(
+            block_on(Some(1).and_then_async(|value| async move { (value > 0).then_some(value) })),
+            Some(1)
+        );
+        assert_eq!(
+            block_on(Some(0).and_then_async(|value| async move { (value > 0).then_some(value) })),
+            None
+        );
+    }
+
+    #[test]
+    fn test_try_filter_async() {
+        assert_eq!(
+            block_on(Some(2).try_filter_async(|&value| async move { Ok::<_, ()>(value > 1) })),
+            Ok(Some(2))
+        );
+        assert_eq!(
+            block_on(Some(0).try_filter_async(|&value| async move { Ok::<_, ()>(value > 1) })),
+            Ok(None)
+        );
+        assert_eq!(
+            block_on(Some(0).try_filter_async(|_| async { Err::<bool, _>("oops") })),
+            Err("oops")
+        );
+    }
+
+    #[test]
+    fn test_unwrap_or_else_async() {
+        assert_eq!(block_on(Some(1).unwrap_or_else_async(|| async { 2 })), 1);
+        assert_eq!(block_on(None.unwrap_or_else_async(|| async { 2 })), 2);
+    }
+
+    #[test]
+    fn test_try_get_or_insert_with_async() {
+        let mut option: Option<Vec<String>> = None;
+        block_on(option.try_get_or_insert_with_async(|| async { Ok::<_, ()>(vec![]) }))
+            .unwrap()
+            .push("foo".to_owned());
+        assert_eq!(option, Some(vec!["foo".to_owned()]));
+
+        assert_eq!(
+            block_on(option.try_get_or_insert_with_async(|| async { Err("oops") })),
+            Ok(&mut vec!["foo".to_owned()])
+        );
+
+        let mut option: Option<i32> = None;
+        assert_eq!(
+            block_on(option.try_get_or_insert_with_async(|| async { Err("oops") })),
+            Err("oops")
+        );
+        assert_eq!(option, None);
+    }
+}
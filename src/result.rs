@@ -0,0 +1,176 @@
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::fmt;
+
+pub trait ResultExt {
+    type Unwrapped;
+    type Error;
+
+    fn matches(self, predicate: impl FnOnce(Self::Unwrapped) -> bool) -> bool;
+    fn is_ok_and_matches(&self, predicate: impl FnOnce(&Self::Unwrapped) -> bool) -> bool;
+    fn is_err_and_matches(&self, predicate: impl FnOnce(&Self::Error) -> bool) -> bool;
+
+    fn try_map<TMapped, TError>(
+        self,
+        mapper: impl FnOnce(Self::Unwrapped) -> Result<TMapped, TError>,
+    ) -> Result<TMapped, TError>
+    where
+        TError: From<Self::Error>;
+
+    fn map_or_default<TMapped: Default>(
+        self,
+        mapper: impl FnOnce(Self::Unwrapped) -> TMapped,
+    ) -> TMapped;
+
+    #[cfg(feature = "alloc")]
+    fn push_if_ok(self, vec: &mut Vec<Self::Unwrapped>);
+
+    #[cfg(feature = "alloc")]
+    fn expect_else(self, message: impl FnOnce() -> String) -> Self::Unwrapped
+    where
+        Self::Error: fmt::Debug;
+}
+
+impl<TValue, TError> ResultExt for Result<TValue, TError> {
+    type Unwrapped = TValue;
+    type Error = TError;
+
+    fn matches(self, predicate: impl FnOnce(TValue) -> bool) -> bool {
+        matches!(self.map(predicate), Ok(true))
+    }
+
+    fn is_ok_and_matches(&self, predicate: impl FnOnce(&TValue) -> bool) -> bool {
+        match self {
+            Ok(value) => predicate(value),
+            Err(_) => false,
+        }
+    }
+
+    fn is_err_and_matches(&self, predicate: impl FnOnce(&TError) -> bool) -> bool {
+        match self {
+            Ok(_) => false,
+            Err(error) => predicate(error),
+        }
+    }
+
+    fn try_map<TMapped, TMapperError>(
+        self,
+        mapper: impl FnOnce(TValue) -> Result<TMapped, TMapperError>,
+    ) -> Result<TMapped, TMapperError>
+    where
+        TMapperError: From<TError>,
+    {
+        mapper(self?)
+    }
+
+    fn map_or_default<TMapped: Default>(self, mapper: impl FnOnce(TValue) -> TMapped) -> TMapped {
+        self.map(mapper).unwrap_or_default()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn push_if_ok(self, vec: &mut Vec<TValue>) {
+        if let Ok(value) = self {
+            vec.push(value);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    fn expect_else(self, message: impl FnOnce() -> String) -> TValue
+    where
+        TError: fmt::Debug,
+    {
+        match self {
+            Ok(value) => value,
+            Err(error) => panic!("{}: {error:?}", message()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches() {
+        assert!(Result::<_, ()>::Ok(3).matches(|value| value > 2));
+        assert!(!Result::<_, ()>::Ok(1).matches(|value| value > 2));
+        assert!(!Err::<i32, _>(()).matches(|_| true));
+    }
+
+    #[test]
+    fn test_is_ok_and_matches() {
+        let result: Result<String, ()> = Ok("foo".to_owned());
+        assert!(result.is_ok_and_matches(|value| value == "foo"));
+        assert!(!result.is_ok_and_matches(|value| value == "bar"));
+        assert!(!Err::<String, _>(()).is_ok_and_matches(|_| true));
+    }
+
+    #[test]
+    fn test_is_err_and_matches() {
+        let result: Result<(), String> = Err("foo".to_owned());
+        assert!(result.is_err_and_matches(|error| error == "foo"));
+        assert!(!result.is_err_and_matches(|error| error == "bar"));
+        assert!(!Ok::<_, String>(()).is_err_and_matches(|_| true));
+    }
+
+    #[test]
+    fn test_try_map() {
+        #[derive(Debug, PartialEq, Eq)]
+        enum Error {
+            Parse,
+            TooSmall,
+        }
+
+        impl From<()> for Error {
+            fn from(_: ()) -> Self {
+                Self::Parse
+            }
+        }
+
+        let check = |value: i32| {
+            if value > 2 {
+                Ok(value * 2)
+            } else {
+                Err(Error::TooSmall)
+            }
+        };
+
+        assert_eq!(Ok::<_, ()>(3).try_map(check), Ok(6));
+        assert_eq!(Ok::<_, ()>(1).try_map(check), Err(Error::TooSmall));
+        assert_eq!(Err::<i32, _>(()).try_map(check), Err(Error::Parse));
+    }
+
+    #[test]
+    #[allow(unstable_name_collisions)]
+    fn test_map_or_default() {
+        assert_eq!(Ok::<_, ()>(2).map_or_default(|value| value + 1), 3);
+        assert_eq!(Err::<i32, _>(()).map_or_default(|value| value + 1), 0);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_push_if_ok() {
+        let mut vec: Vec<String> = Default::default();
+        Ok::<_, ()>("foo".to_owned()).push_if_ok(&mut vec);
+        assert_eq!(vec, vec!["foo".to_owned()]);
+        Err(()).push_if_ok(&mut vec);
+        assert_eq!(vec, vec!["foo".to_owned()]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[should_panic(expected = "Hi: \"oops\"")]
+    fn test_expect_else_panics() {
+        Err::<String, _>("oops").expect_else(|| "Hi".to_owned());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_expect_else() {
+        assert_eq!(
+            Ok::<_, ()>("foo".to_owned()).expect_else(|| "Hi".to_owned()),
+            "foo".to_owned(),
+        );
+    }
+}
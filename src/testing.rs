@@ -0,0 +1,92 @@
+use std::{cell::RefCell, fmt};
+
+thread_local! {
+    static CAPTURED: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+pub(crate) fn print_line(args: fmt::Arguments<'_>) {
+    let printed = CAPTURED.with_borrow_mut(|captured| match captured {
+        Some(captured) => {
+            fmt::write(captured, args).unwrap();
+            captured.push('\n');
+            false
+        }
+        None => true,
+    });
+    if printed {
+        println!("{args}");
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CapturedLog {
+    output: String,
+}
+
+impl CapturedLog {
+    pub fn capture<TReturn>(callback: impl FnOnce() -> TReturn) -> (TReturn, Self) {
+        struct RestoreOnDrop(Option<String>);
+
+        impl Drop for RestoreOnDrop {
+            fn drop(&mut self) {
+                let previous = self.0.take();
+                CAPTURED.with_borrow_mut(|captured| *captured = previous);
+            }
+        }
+
+        let _restore =
+            RestoreOnDrop(CAPTURED.with_borrow_mut(|captured| captured.replace(String::default())));
+        let ret = callback();
+        let output = CAPTURED.with_borrow_mut(Option::take).unwrap_or_default();
+        (ret, Self { output })
+    }
+
+    pub fn output(&self) -> &str {
+        &self.output
+    }
+
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.output.lines()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capture() {
+        let (ret, captured) = CapturedLog::capture(|| {
+            print_line(format_args!("foo: {}", 1));
+            print_line(format_args!("bar"));
+            2
+        });
+        assert_eq!(ret, 2);
+        assert_eq!(captured.output(), "foo: 1\nbar\n");
+        assert_eq!(captured.lines().collect::<Vec<_>>(), vec!["foo: 1", "bar"]);
+    }
+
+    #[test]
+    fn test_capture_nested() {
+        let ((_, inner), outer) = CapturedLog::capture(|| {
+            print_line(format_args!("outer"));
+            let inner = CapturedLog::capture(|| print_line(format_args!("inner")));
+            print_line(format_args!("outer again"));
+            inner
+        });
+        assert_eq!(inner.output(), "inner\n");
+        assert_eq!(outer.output(), "outer\nouter again\n");
+    }
+
+    #[test]
+    fn test_capture_restores_after_panic() {
+        let result = std::panic::catch_unwind(|| {
+            CapturedLog::capture(|| {
+                print_line(format_args!("foo"));
+                panic!("oops");
+            })
+        });
+        assert!(result.is_err());
+        CAPTURED.with_borrow(|captured| assert_eq!(*captured, None));
+    }
+}
@@ -1,12 +1,51 @@
-use std::cmp::Ordering;
+use alloc::{borrow::ToOwned, collections::VecDeque, vec, vec::Vec};
+use core::{
+    cmp::Ordering,
+    mem,
+    ops::{Deref, RangeBounds},
+};
+
+use itertools::{EitherOrBoth, Itertools};
+#[cfg(feature = "rand")]
+use rand::{seq::SliceRandom, Rng};
+
+use crate::SortedSlice;
 
 pub trait VecExt {
     type Item;
 
     fn and_push(self, item: Self::Item) -> Self;
     fn and_extend(self, iter: impl IntoIterator<Item = Self::Item>) -> Self;
+    fn and_retain(self, predicate: impl FnMut(&Self::Item) -> bool) -> Self;
+    fn and_insert(self, index: usize, item: Self::Item) -> Self;
+    fn and_remove(self, index: usize) -> Self;
+    fn and_truncate(self, len: usize) -> Self;
+    fn and_drain(self, range: impl RangeBounds<usize>) -> Self;
+    fn and_resize_with(self, new_len: usize, f: impl FnMut() -> Self::Item) -> Self;
+    fn and_reverse(self) -> Self;
+    fn and_rotate_left(self, mid: usize) -> Self;
+    fn and_rotate_right(self, k: usize) -> Self;
+    fn and_dedup_by(
+        self,
+        same_bucket: impl FnMut(&mut Self::Item, &mut Self::Item) -> bool,
+    ) -> Self;
+    fn and_dedup_by_key<TKey: PartialEq>(self, key: impl FnMut(&mut Self::Item) -> TKey) -> Self;
     fn and_sort_by(self, compare: impl FnMut(&Self::Item, &Self::Item) -> Ordering) -> Self;
+    fn and_sort_by_key<TKey: Ord>(self, f: impl FnMut(&Self::Item) -> TKey) -> Self;
+    fn and_sort_by_cached_key<TKey: Ord>(self, f: impl FnMut(&Self::Item) -> TKey) -> Self;
+    fn and_sort_unstable_by(
+        self,
+        compare: impl FnMut(&Self::Item, &Self::Item) -> Ordering,
+    ) -> Self;
+    fn and_sort_unstable_by_key<TKey: Ord>(self, f: impl FnMut(&Self::Item) -> TKey) -> Self;
+    #[cfg(feature = "rand")]
+    fn and_shuffle_with<TRng: Rng + ?Sized>(self, rng: &mut TRng) -> Self;
     fn append_to(self, other: &mut Vec<Self::Item>);
+    fn insert_sorted_by_key<TKey: Ord>(
+        &mut self,
+        item: Self::Item,
+        f: impl FnMut(&Self::Item) -> TKey,
+    ) -> usize;
 }
 
 impl<TItem> VecExt for Vec<TItem> {
@@ -22,20 +61,113 @@ impl<TItem> VecExt for Vec<TItem> {
         self
     }
 
+    fn and_retain(mut self, predicate: impl FnMut(&TItem) -> bool) -> Self {
+        self.retain(predicate);
+        self
+    }
+
+    fn and_insert(mut self, index: usize, item: TItem) -> Self {
+        self.insert(index, item);
+        self
+    }
+
+    fn and_remove(mut self, index: usize) -> Self {
+        self.remove(index);
+        self
+    }
+
+    fn and_truncate(mut self, len: usize) -> Self {
+        self.truncate(len);
+        self
+    }
+
+    fn and_drain(mut self, range: impl RangeBounds<usize>) -> Self {
+        self.drain(range);
+        self
+    }
+
+    fn and_resize_with(mut self, new_len: usize, f: impl FnMut() -> TItem) -> Self {
+        self.resize_with(new_len, f);
+        self
+    }
+
+    fn and_reverse(mut self) -> Self {
+        self.reverse();
+        self
+    }
+
+    fn and_rotate_left(mut self, mid: usize) -> Self {
+        self.rotate_left(mid);
+        self
+    }
+
+    fn and_rotate_right(mut self, k: usize) -> Self {
+        self.rotate_right(k);
+        self
+    }
+
+    fn and_dedup_by(mut self, same_bucket: impl FnMut(&mut TItem, &mut TItem) -> bool) -> Self {
+        self.dedup_by(same_bucket);
+        self
+    }
+
+    fn and_dedup_by_key<TKey: PartialEq>(mut self, key: impl FnMut(&mut TItem) -> TKey) -> Self {
+        self.dedup_by_key(key);
+        self
+    }
+
     fn and_sort_by(mut self, compare: impl FnMut(&Self::Item, &Self::Item) -> Ordering) -> Self {
         self.sort_by(compare);
         self
     }
 
+    fn and_sort_by_key<TKey: Ord>(mut self, f: impl FnMut(&TItem) -> TKey) -> Self {
+        self.sort_by_key(f);
+        self
+    }
+
+    fn and_sort_by_cached_key<TKey: Ord>(mut self, f: impl FnMut(&TItem) -> TKey) -> Self {
+        self.sort_by_cached_key(f);
+        self
+    }
+
+    fn and_sort_unstable_by(mut self, compare: impl FnMut(&TItem, &TItem) -> Ordering) -> Self {
+        self.sort_unstable_by(compare);
+        self
+    }
+
+    fn and_sort_unstable_by_key<TKey: Ord>(mut self, f: impl FnMut(&TItem) -> TKey) -> Self {
+        self.sort_unstable_by_key(f);
+        self
+    }
+
+    #[cfg(feature = "rand")]
+    fn and_shuffle_with<TRng: Rng + ?Sized>(mut self, rng: &mut TRng) -> Self {
+        self.shuffle(rng);
+        self
+    }
+
     fn append_to(mut self, other: &mut Vec<Self::Item>) {
         other.append(&mut self);
     }
+
+    fn insert_sorted_by_key<TKey: Ord>(
+        &mut self,
+        item: TItem,
+        mut f: impl FnMut(&TItem) -> TKey,
+    ) -> usize {
+        let key = f(&item);
+        let index = self.partition_point(|value| f(value) <= key);
+        self.insert(index, item);
+        index
+    }
 }
 
 pub trait VecExtOrd {
     type Item: Ord;
 
     fn and_sort(self) -> Self;
+    fn and_sort_unstable(self) -> Self;
 }
 
 impl<TItem: Ord> VecExtOrd for Vec<TItem> {
@@ -45,48 +177,316 @@ impl<TItem: Ord> VecExtOrd for Vec<TItem> {
         self.sort();
         self
     }
+
+    fn and_sort_unstable(mut self) -> Self {
+        self.sort_unstable();
+        self
+    }
+}
+
+pub trait SortedVecExt {
+    type Item: Ord;
+
+    fn insert_sorted(&mut self, item: Self::Item) -> usize;
+    fn remove_sorted(&mut self, item: &Self::Item) -> Option<Self::Item>;
+    fn contains_sorted(&self, item: &Self::Item) -> bool;
+    fn merge_sorted(self, other: impl IntoIterator<Item = Self::Item>) -> Self;
+    fn dedup_sorted_union(self, other: impl IntoIterator<Item = Self::Item>) -> Self;
+    fn dedup_sorted_intersection(self, other: impl IntoIterator<Item = Self::Item>) -> Self;
+    fn dedup_sorted_difference(self, other: impl IntoIterator<Item = Self::Item>) -> Self;
+}
+
+impl<TItem: Ord> SortedVecExt for Vec<TItem> {
+    type Item = TItem;
+
+    fn insert_sorted(&mut self, item: TItem) -> usize {
+        let index = self.partition_point(|value| *value <= item);
+        self.insert(index, item);
+        index
+    }
+
+    fn remove_sorted(&mut self, item: &TItem) -> Option<TItem> {
+        self.binary_search(item)
+            .ok()
+            .map(|index| self.remove(index))
+    }
+
+    fn contains_sorted(&self, item: &TItem) -> bool {
+        self.binary_search(item).is_ok()
+    }
+
+    fn merge_sorted(self, other: impl IntoIterator<Item = TItem>) -> Self {
+        self.into_iter().merge(other).collect()
+    }
+
+    fn dedup_sorted_union(self, other: impl IntoIterator<Item = TItem>) -> Self {
+        self.into_iter()
+            .dedup()
+            .merge_join_by(other.into_iter().dedup(), Ord::cmp)
+            .map(|either_or_both| either_or_both.reduce(|left, _| left))
+            .collect()
+    }
+
+    fn dedup_sorted_intersection(self, other: impl IntoIterator<Item = TItem>) -> Self {
+        self.into_iter()
+            .dedup()
+            .merge_join_by(other.into_iter().dedup(), Ord::cmp)
+            .filter_map(|either_or_both| match either_or_both {
+                EitherOrBoth::Both(left, _) => Some(left),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn dedup_sorted_difference(self, other: impl IntoIterator<Item = TItem>) -> Self {
+        self.into_iter()
+            .dedup()
+            .merge_join_by(other.into_iter().dedup(), Ord::cmp)
+            .filter_map(|either_or_both| match either_or_both {
+                EitherOrBoth::Left(left) => Some(left),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SortedVec<T>(Vec<T>);
+
+impl<T: Ord> SortedVec<T> {
+    pub fn new() -> Self {
+        Self(vec![])
+    }
+
+    pub fn from_vec(vec: Vec<T>) -> Self {
+        Self(vec.and_sort())
+    }
+
+    pub fn insert(&mut self, item: T) -> usize {
+        self.0.insert_sorted(item)
+    }
+
+    pub fn remove(&mut self, item: &T) -> Option<T> {
+        self.0.remove_sorted(item)
+    }
+
+    pub fn contains(&self, item: &T) -> bool {
+        self.0.contains_sorted(item)
+    }
+
+    pub fn merge(self, other: Self) -> Self {
+        Self(self.0.merge_sorted(other.0))
+    }
+
+    pub fn as_sorted_slice(&self) -> SortedSlice<'_, T> {
+        SortedSlice(&self.0)
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T> Deref for SortedVec<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T: Ord> From<Vec<T>> for SortedVec<T> {
+    fn from(vec: Vec<T>) -> Self {
+        Self::from_vec(vec)
+    }
+}
+
+impl<T> From<SortedVec<T>> for Vec<T> {
+    fn from(sorted_vec: SortedVec<T>) -> Self {
+        sorted_vec.0
+    }
+}
+
+impl<T: Ord> FromIterator<T> for SortedVec<T> {
+    fn from_iter<TIterator: IntoIterator<Item = T>>(iter: TIterator) -> Self {
+        Self::from_vec(iter.into_iter().collect())
+    }
+}
+
+impl<T: Ord> Extend<T> for SortedVec<T> {
+    fn extend<TIterator: IntoIterator<Item = T>>(&mut self, iter: TIterator) {
+        let batch = iter.into_iter().collect::<Vec<_>>();
+        if batch.is_empty() {
+            return;
+        }
+        self.0 = mem::take(&mut self.0).merge_sorted(batch.and_sort());
+    }
+}
+
+impl<T> IntoIterator for SortedVec<T> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    fn into_iter(self) -> vec::IntoIter<T> {
+        self.0.into_iter()
+    }
+}
+
+pub trait VecExtPartialEq {
+    type Item: PartialEq;
+
+    fn and_dedup(self) -> Self;
+}
+
+impl<TItem: PartialEq> VecExtPartialEq for Vec<TItem> {
+    type Item = TItem;
+
+    fn and_dedup(mut self) -> Self {
+        self.dedup();
+        self
+    }
+}
+
+pub trait VecExtClone {
+    type Item: Clone;
+
+    fn and_resize(self, new_len: usize, value: Self::Item) -> Self;
+}
+
+impl<TItem: Clone> VecExtClone for Vec<TItem> {
+    type Item = TItem;
+
+    fn and_resize(mut self, new_len: usize, value: TItem) -> Self {
+        self.resize(new_len, value);
+        self
+    }
 }
 
 pub trait SliceExtClone {
     type Item: Clone;
 
+    fn sorted_by(
+        self,
+        compare: impl FnMut(&Self::Item, &Self::Item) -> Ordering,
+    ) -> Vec<Self::Item>;
+
     fn sorted_by_key<TKey>(self, f: impl FnMut(&Self::Item) -> TKey) -> Vec<Self::Item>
     where
         TKey: Ord;
-}
 
-impl<TItem: Clone + Ord> SliceExtClone for &[TItem] {
-    type Item = TItem;
+    fn sorted_by_cached_key<TKey>(self, f: impl FnMut(&Self::Item) -> TKey) -> Vec<Self::Item>
+    where
+        TKey: Ord;
 
-    fn sorted_by_key<TKey>(self, f: impl FnMut(&Self::Item) -> TKey) -> Vec<Self::Item>
+    fn sorted_unstable_by(
+        self,
+        compare: impl FnMut(&Self::Item, &Self::Item) -> Ordering,
+    ) -> Vec<Self::Item>;
+
+    fn sorted_unstable_by_key<TKey>(self, f: impl FnMut(&Self::Item) -> TKey) -> Vec<Self::Item>
     where
-        TKey: Ord,
-    {
-        let mut cloned = self.to_owned();
-        cloned.sort_by_key(f);
-        cloned
-    }
+        TKey: Ord;
+
+    fn reversed(self) -> Vec<Self::Item>;
+}
+
+macro_rules! impl_slice_ext_clone {
+    ($type:ty, $this:ident => $to_vec:expr $(,)?) => {
+        impl<TItem: Clone> SliceExtClone for $type {
+            type Item = TItem;
+
+            fn sorted_by(self, compare: impl FnMut(&TItem, &TItem) -> Ordering) -> Vec<TItem> {
+                let $this = self;
+                $to_vec.and_sort_by(compare)
+            }
+
+            fn sorted_by_key<TKey>(self, f: impl FnMut(&TItem) -> TKey) -> Vec<TItem>
+            where
+                TKey: Ord,
+            {
+                let $this = self;
+                $to_vec.and_sort_by_key(f)
+            }
+
+            fn sorted_by_cached_key<TKey>(self, f: impl FnMut(&TItem) -> TKey) -> Vec<TItem>
+            where
+                TKey: Ord,
+            {
+                let $this = self;
+                $to_vec.and_sort_by_cached_key(f)
+            }
+
+            fn sorted_unstable_by(
+                self,
+                compare: impl FnMut(&TItem, &TItem) -> Ordering,
+            ) -> Vec<TItem> {
+                let $this = self;
+                $to_vec.and_sort_unstable_by(compare)
+            }
+
+            fn sorted_unstable_by_key<TKey>(self, f: impl FnMut(&TItem) -> TKey) -> Vec<TItem>
+            where
+                TKey: Ord,
+            {
+                let $this = self;
+                $to_vec.and_sort_unstable_by_key(f)
+            }
+
+            fn reversed(self) -> Vec<TItem> {
+                let $this = self;
+                $to_vec.and_reverse()
+            }
+        }
+    };
 }
 
+impl_slice_ext_clone!(&[TItem], this => this.to_owned());
+impl_slice_ext_clone!(&Vec<TItem>, this => this.clone());
+impl_slice_ext_clone!(&VecDeque<TItem>, this => this.iter().cloned().collect::<Vec<_>>());
+
 pub trait SliceExtCloneOrd {
     type Item: Clone + Ord;
 
     fn sorted(self) -> Vec<Self::Item>;
+    fn sorted_unstable(self) -> Vec<Self::Item>;
+    fn dedup_sorted(self) -> Vec<Self::Item>;
+    fn to_sorted_vec(self) -> Vec<Self::Item>;
 }
 
-impl<TItem: Clone + Ord> SliceExtCloneOrd for &[TItem] {
-    type Item = TItem;
+macro_rules! impl_slice_ext_clone_ord {
+    ($type:ty, $this:ident => $to_vec:expr $(,)?) => {
+        impl<TItem: Clone + Ord> SliceExtCloneOrd for $type {
+            type Item = TItem;
 
-    fn sorted(self) -> Vec<TItem> {
-        let mut cloned = self.to_owned();
-        cloned.sort();
-        cloned
-    }
+            fn sorted(self) -> Vec<TItem> {
+                let $this = self;
+                $to_vec.and_sort()
+            }
+
+            fn sorted_unstable(self) -> Vec<TItem> {
+                let $this = self;
+                $to_vec.and_sort_unstable()
+            }
+
+            fn dedup_sorted(self) -> Vec<TItem> {
+                self.sorted().and_dedup()
+            }
+
+            fn to_sorted_vec(self) -> Vec<TItem> {
+                self.sorted()
+            }
+        }
+    };
 }
 
+impl_slice_ext_clone_ord!(&[TItem], this => this.to_owned());
+impl_slice_ext_clone_ord!(&Vec<TItem>, this => this.clone());
+impl_slice_ext_clone_ord!(&VecDeque<TItem>, this => this.iter().cloned().collect::<Vec<_>>());
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Contains;
 
     #[test]
     fn test_and_push() {
@@ -103,6 +503,212 @@ mod tests {
         assert_eq!(vec![1, 3, 2].and_sort(), vec![1, 2, 3]);
     }
 
+    #[test]
+    fn test_and_retain() {
+        assert_eq!(vec![1, 2, 3].and_retain(|&value| value != 2), vec![1, 3]);
+    }
+
+    #[test]
+    fn test_and_dedup() {
+        assert_eq!(vec![1, 1, 2, 1].and_dedup(), vec![1, 2, 1]);
+    }
+
+    #[test]
+    fn test_and_dedup_by_key() {
+        assert_eq!(
+            vec![1, 3, 2, 4].and_dedup_by_key(|value| *value % 2),
+            vec![1, 2]
+        );
+    }
+
+    #[test]
+    fn test_and_dedup_by() {
+        assert_eq!(
+            vec!["foo", "Foo", "bar"].and_dedup_by(|a, b| a.eq_ignore_ascii_case(b)),
+            vec!["foo", "bar"]
+        );
+    }
+
+    #[test]
+    fn test_and_sort_by_key() {
+        assert_eq!(
+            vec![-3, 1, -2].and_sort_by_key(|value: &i32| value.abs()),
+            vec![1, -2, -3]
+        );
+    }
+
+    #[test]
+    fn test_and_sort_by_cached_key() {
+        assert_eq!(
+            vec![10, 9, 100].and_sort_by_cached_key(|value| value.to_string()),
+            vec![10, 100, 9]
+        );
+    }
+
+    #[test]
+    fn test_and_sort_unstable() {
+        assert_eq!(vec![3, 1, 2].and_sort_unstable(), vec![1, 2, 3]);
+        assert_eq!(
+            vec![1, 3, 2].and_sort_unstable_by(|a, b| b.cmp(a)),
+            vec![3, 2, 1]
+        );
+        assert_eq!(
+            vec![-3, 1, -2].and_sort_unstable_by_key(|value: &i32| value.abs()),
+            vec![1, -2, -3]
+        );
+    }
+
+    #[test]
+    fn test_and_reverse() {
+        assert_eq!(vec![1, 2, 3].and_reverse(), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn test_and_truncate() {
+        assert_eq!(vec![1, 2, 3].and_truncate(1), vec![1]);
+        assert_eq!(vec![1].and_truncate(2), vec![1]);
+    }
+
+    #[test]
+    fn test_and_drain() {
+        assert_eq!(vec![1, 2, 3, 4].and_drain(1..3), vec![1, 4]);
+        assert_eq!(vec![1, 2, 3].and_drain(..), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn test_and_insert() {
+        assert_eq!(vec![1, 3].and_insert(1, 2), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_and_remove() {
+        assert_eq!(vec![1, 2, 3].and_remove(0), vec![2, 3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_and_remove_out_of_bounds_panics() {
+        vec![1].and_remove(1);
+    }
+
+    #[test]
+    fn test_and_rotate() {
+        assert_eq!(vec![1, 2, 3].and_rotate_left(1), vec![2, 3, 1]);
+        assert_eq!(vec![1, 2, 3].and_rotate_right(1), vec![3, 1, 2]);
+    }
+
+    #[test]
+    fn test_and_resize() {
+        assert_eq!(vec![1].and_resize(3, 0), vec![1, 0, 0]);
+        assert_eq!(vec![1, 2].and_resize(1, 0), vec![1]);
+        assert_eq!(
+            Vec::<i32>::new().and_resize_with(2, Default::default),
+            vec![0, 0]
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_and_shuffle_with() {
+        use rand::rngs::mock::StepRng;
+
+        assert_eq!(
+            (0..10)
+                .collect::<Vec<_>>()
+                .and_shuffle_with(&mut StepRng::new(0, 0x9E37_79B9_7F4A_7C15)),
+            vec![8, 9, 1, 7, 5, 2, 6, 3, 4, 0]
+        );
+    }
+
+    #[test]
+    fn test_insert_sorted() {
+        let mut vec = vec![1, 3, 5];
+        assert_eq!(vec.insert_sorted(4), 2);
+        assert_eq!(vec.insert_sorted(0), 0);
+        assert_eq!(vec.insert_sorted(5), 5);
+        assert_eq!(vec, vec![0, 1, 3, 4, 5, 5]);
+    }
+
+    #[test]
+    fn test_insert_sorted_by_key() {
+        let mut vec = vec!["a", "ccc"];
+        assert_eq!(vec.insert_sorted_by_key("bb", |value| value.len()), 1);
+        assert_eq!(vec, vec!["a", "bb", "ccc"]);
+
+        let mut floats = vec![-1.0_f64, 2.5];
+        assert_eq!(
+            floats.insert_sorted_by_key(0.5, |value| (*value * 10.0) as i64),
+            1
+        );
+        assert_eq!(floats, vec![-1.0, 0.5, 2.5]);
+    }
+
+    #[test]
+    fn test_remove_sorted() {
+        let mut vec = vec![1, 2, 3];
+        assert_eq!(vec.remove_sorted(&2), Some(2));
+        assert_eq!(vec.remove_sorted(&2), None);
+        assert_eq!(vec, vec![1, 3]);
+    }
+
+    #[test]
+    fn test_contains_sorted() {
+        assert!(vec![1, 2, 3].contains_sorted(&3));
+        assert!(!vec![1, 2, 3].contains_sorted(&4));
+    }
+
+    #[test]
+    fn test_merge_sorted() {
+        assert_eq!(
+            vec![1, 3, 5].merge_sorted([2, 3, 6]),
+            vec![1, 2, 3, 3, 5, 6]
+        );
+    }
+
+    #[test]
+    fn test_dedup_sorted_set_operations() {
+        assert_eq!(
+            vec![1, 1, 3, 5].dedup_sorted_union([2, 3, 6]),
+            vec![1, 2, 3, 5, 6]
+        );
+        assert_eq!(
+            vec![1, 3, 3, 5].dedup_sorted_intersection([3, 3, 5, 6]),
+            vec![3, 5]
+        );
+        assert_eq!(vec![1, 1, 3, 5].dedup_sorted_difference([3, 6]), vec![1, 5]);
+    }
+
+    #[test]
+    fn test_dedup_sorted_set_operations_with_duplicates() {
+        assert_eq!(
+            vec![1, 2, 2, 3].dedup_sorted_union([2, 2, 2, 4, 4]),
+            vec![1, 2, 3, 4]
+        );
+        assert_eq!(
+            vec![2, 2, 3, 3].dedup_sorted_intersection([2, 3, 3, 3]),
+            vec![2, 3]
+        );
+        assert_eq!(vec![1, 2, 2, 3].dedup_sorted_difference([2]), vec![1, 3]);
+        assert_eq!(vec![3, 3].dedup_sorted_difference([3]), Vec::<i32>::new());
+        assert_eq!(vec![1, 1, 2].dedup_sorted_difference([2, 2]), vec![1]);
+    }
+
+    #[test]
+    fn test_sorted_vec() {
+        let mut sorted_vec = SortedVec::from(vec![3, 1]);
+        sorted_vec.insert(2);
+        sorted_vec.extend([4, 0]);
+        assert_eq!(*sorted_vec, [0, 1, 2, 3, 4]);
+        sorted_vec.extend([]);
+        assert_eq!(*sorted_vec, [0, 1, 2, 3, 4]);
+        assert!(sorted_vec.contains(&4));
+        assert_eq!(sorted_vec.remove(&4), Some(4));
+        assert!(!sorted_vec.as_sorted_slice().contains_(&4));
+
+        let merged = sorted_vec.merge([5, -1].into_iter().collect());
+        assert_eq!(merged.into_vec(), vec![-1, 0, 1, 2, 3, 5]);
+    }
+
     #[test]
     fn test_sorted() {
         let foo = vec![1, 3, 2];
@@ -111,6 +717,64 @@ mod tests {
         assert_eq!(foo, vec![1, 3, 2]);
     }
 
+    #[test]
+    fn test_sorted_by_key_without_ord() {
+        let floats = [2.5_f64, -1.0, 0.5];
+        assert_eq!(
+            floats.sorted_by_key(|value| (*value * 10.0) as i64),
+            vec![-1.0, 0.5, 2.5]
+        );
+        assert_eq!(
+            floats.sorted_by(|a, b| b.total_cmp(a)),
+            vec![2.5, 0.5, -1.0]
+        );
+        assert_eq!(
+            floats.sorted_unstable_by(f64::total_cmp),
+            vec![-1.0, 0.5, 2.5]
+        );
+    }
+
+    #[test]
+    fn test_sorted_by_cached_key() {
+        let vec = vec![10, 9, 100];
+        assert_eq!(
+            vec.sorted_by_cached_key(|value| value.to_string()),
+            vec![10, 100, 9]
+        );
+        assert_eq!(vec.sorted_unstable_by_key(|value| -value), vec![100, 10, 9]);
+        assert_eq!(vec, vec![10, 9, 100]);
+    }
+
+    #[test]
+    fn test_sorted_through_vec_ref() {
+        let vec = vec![3, 1, 2];
+        let vec_ref = &vec;
+        assert_eq!(vec_ref.sorted(), vec![1, 2, 3]);
+        assert_eq!(vec_ref.sorted_unstable(), vec![1, 2, 3]);
+        assert_eq!(vec_ref.reversed(), vec![2, 1, 3]);
+    }
+
+    #[test]
+    fn test_dedup_sorted() {
+        assert_eq!([3, 1, 3, 2, 1].dedup_sorted(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_reversed() {
+        assert_eq!(["foo", "bar"].reversed(), vec!["bar", "foo"]);
+    }
+
+    #[test]
+    fn test_vec_deque() {
+        let mut vec_deque = VecDeque::from([2, 3]);
+        vec_deque.push_front(1);
+        vec_deque.push_front(3);
+        assert_eq!(vec_deque.to_sorted_vec(), vec![1, 2, 3, 3]);
+        assert_eq!(vec_deque.dedup_sorted(), vec![1, 2, 3]);
+        assert_eq!(vec_deque.reversed(), vec![3, 2, 1, 3]);
+        assert_eq!(vec_deque.sorted_by_key(|value| -value), vec![3, 3, 2, 1]);
+    }
+
     #[test]
     fn test_append_to() {
         let mut ret = vec![3, 4];
@@ -0,0 +1,67 @@
+#![cfg(feature = "std")]
+
+use std::collections::HashMap;
+
+use squalid::{HashMapExt, VecExt};
+
+#[test]
+fn test_vec_ext_and_extend() {
+    assert_eq!(vec![1].and_extend([2, 3]), vec![1, 2, 3]);
+}
+
+#[test]
+fn test_vec_ext_and_retain() {
+    assert_eq!(vec![1, 2, 3].and_retain(|&value| value != 2), vec![1, 3]);
+}
+
+#[test]
+fn test_hash_map_ext_and_extend() {
+    assert_eq!(
+        HashMap::from([("foo", 1)]).and_extend([("bar", 2)]),
+        HashMap::from([("foo", 1), ("bar", 2)])
+    );
+}
+
+mod glob_import {
+    use std::collections::HashMap;
+
+    use squalid::*;
+
+    #[test]
+    fn test_and_extend() {
+        assert_eq!(vec![1].and_extend([2, 3]), vec![1, 2, 3]);
+        assert_eq!(
+            HashMap::from([("foo", 1)]).and_extend([("bar", 2)]),
+            HashMap::from([("foo", 1), ("bar", 2)])
+        );
+    }
+
+    #[test]
+    fn test_and_retain() {
+        assert_eq!(vec![1, 2, 3].and_retain(|&value| value != 2), vec![1, 3]);
+        assert_eq!(
+            HashMap::from([("foo", 1), ("bar", 2)]).and_retain(|_, value| *value != 2),
+            HashMap::from([("foo", 1)])
+        );
+    }
+}
+
+mod itertools_glob_import {
+    use std::collections::HashMap;
+
+    use itertools::Itertools;
+    use squalid::*;
+
+    #[test]
+    fn test_group_into_map_by() {
+        let grouped: HashMap<usize, Vec<&str>> = ["foo", "ba", "bar"]
+            .into_iter()
+            .group_into_map_by(|value| value.len());
+        assert_eq!(
+            grouped,
+            ["foo", "ba", "bar"]
+                .into_iter()
+                .into_group_map_by(|value| value.len())
+        );
+    }
+}
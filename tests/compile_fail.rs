@@ -0,0 +1,6 @@
+#![cfg(all(feature = "macros", feature = "regex"))]
+
+#[test]
+fn test_compile_fail() {
+    trybuild::TestCases::new().compile_fail("tests/ui/*.rs");
+}
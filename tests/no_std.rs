@@ -0,0 +1,45 @@
+use std::{env, path::Path, process::Command};
+
+const DEFAULT_TARGET: &str = "thumbv7em-none-eabihf";
+
+fn is_target_installed(target: &str) -> bool {
+    let output = Command::new(env::var("RUSTC").unwrap_or_else(|_| "rustc".to_owned()))
+        .args(["--print", "target-libdir", "--target", target])
+        .output()
+        .unwrap();
+    output.status.success() && Path::new(String::from_utf8(output.stdout).unwrap().trim()).exists()
+}
+
+#[test]
+#[ignore = "requires a no_std target, e.g. thumbv7em-none-eabihf"]
+fn test_compiles_for_no_std_target() {
+    let target = env::var("SQUALID_NO_STD_TARGET").unwrap_or_else(|_| DEFAULT_TARGET.to_owned());
+    assert!(
+        is_target_installed(&target),
+        "target {target} is not installed (`rustup target add {target}`)"
+    );
+
+    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+    for features in ["", "alloc", "alloc,async"] {
+        let status = Command::new(env!("CARGO"))
+            .current_dir(manifest_dir)
+            .args([
+                "check",
+                "--lib",
+                "--no-default-features",
+                "--target",
+                &target,
+            ])
+            .args(["--features", features])
+            .env(
+                "CARGO_TARGET_DIR",
+                Path::new(manifest_dir).join("target").join("no-std"),
+            )
+            .status()
+            .unwrap();
+        assert!(
+            status.success(),
+            "failed to compile for {target} with features {features:?}"
+        );
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    squalid::regex!(r"^f(o+$");
+}